@@ -0,0 +1,90 @@
+"""Compare the serial and multithreaded paths of the row-wise expressions.
+
+Each run happens in a child process because the plugin reads POLARS_MAX_THREADS once, when its
+thread pool starts. The outputs of both runs are checked for equality before reporting timings.
+
+Usage: python benches/bench_parallel.py [row_count]
+"""
+
+import json
+import os
+import subprocess
+import sys
+import tempfile
+import time
+from pathlib import Path
+
+import polars
+from polars.testing import assert_frame_equal
+
+from eruo_strutil import extract_all_regex, replace_regex, split_by_chars, to_sentence_case, to_snake_case
+
+DEFAULT_ROW_COUNT = 10_000_000
+
+SAMPLES = [
+    'helloWorld, foo bar',
+    None,
+    'XMLHttpRequest returned 404 after 3 retries',
+    'the end. the start! what now?',
+    'snake_case_value, kebab-case-value',
+    '',
+]
+
+EXPRESSIONS = {
+    'to_sentence_case': lambda: to_sentence_case('input'),
+    'to_snake_case': lambda: to_snake_case('input'),
+    'split_by_chars': lambda: split_by_chars('input', characters=','),
+    'replace_regex': lambda: replace_regex('input', pattern=r'\d+', replacement='#'),
+    'extract_all_regex': lambda: extract_all_regex('input', pattern=r'\w+', group=0),
+}
+
+
+def run_child(row_count: int, output_path: Path) -> None:
+    df = polars.DataFrame({'input': polars.Series(SAMPLES).sample(row_count, with_replacement=True, seed=0)})
+
+    timings = {}
+    outputs = {}
+    for name, expression in EXPRESSIONS.items():
+        start = time.perf_counter()
+        outputs[name] = df.select(output=expression()).to_series()
+        timings[name] = time.perf_counter() - start
+
+    polars.DataFrame(outputs).write_ipc(output_path)
+    print(json.dumps(timings))
+
+
+def run(row_count: int, thread_count: int | None, output_path: Path) -> dict[str, float]:
+    env = dict(os.environ)
+    if thread_count is None:
+        env.pop('POLARS_MAX_THREADS', None)
+    else:
+        env['POLARS_MAX_THREADS'] = str(thread_count)
+
+    command = [sys.executable, __file__, '--child', str(row_count), str(output_path)]
+    result = subprocess.run(command, env=env, check=True, capture_output=True, text=True)
+    return json.loads(result.stdout)
+
+
+def main() -> None:
+    row_count = int(sys.argv[1]) if len(sys.argv) > 1 else DEFAULT_ROW_COUNT
+
+    with tempfile.TemporaryDirectory() as directory:
+        serial_path = Path(directory) / 'serial.ipc'
+        parallel_path = Path(directory) / 'parallel.ipc'
+
+        serial = run(row_count, 1, serial_path)
+        parallel = run(row_count, None, parallel_path)
+
+        assert_frame_equal(polars.read_ipc(serial_path), polars.read_ipc(parallel_path))
+
+    print(f'{row_count:,} rows, outputs are identical')
+    for name in EXPRESSIONS:
+        speedup = serial[name] / parallel[name]
+        print(f'{name:<20} serial {serial[name]:>8.3f}s  parallel {parallel[name]:>8.3f}s  speedup {speedup:>5.2f}x')
+
+
+if __name__ == '__main__':
+    if len(sys.argv) == 4 and sys.argv[1] == '--child':
+        run_child(int(sys.argv[2]), Path(sys.argv[3]))
+    else:
+        main()
@@ -14,35 +14,1610 @@ if TYPE_CHECKING:
 LIB = Path(__file__).parent
 
 
-def pig_latinnify(expression: IntoExprColumn) -> pl.Expr:
+def pig_latinnify(expression: IntoExprColumn,
+                  vowel_suffix: str = 'way',
+                  consonant_suffix: str = 'ay',
+                  treat_y_as_vowel: bool = False,
+                  null_mode: str = 'propagate') -> pl.Expr:
     return register_plugin_function(
         plugin_path=LIB,
         function_name='pig_latinnify',
         args=[expression],
+        kwargs={
+            'vowel_suffix': vowel_suffix,
+            'consonant_suffix': consonant_suffix,
+            'treat_y_as_vowel': treat_y_as_vowel,
+            'null_mode': null_mode,
+        },
         is_elementwise=True,
     )
 
-def split_by_chars(expression: IntoExprColumn, characters: str) -> pl.Expr:
+def split_by_chars(expression: IntoExprColumn,
+                   characters: str,
+                   keep_empty: bool = True,
+                   maxsplit: int | None = None,
+                   null_mode: str = 'propagate') -> pl.Expr:
     return register_plugin_function(
         plugin_path=LIB,
         function_name='split_by_chars',
         args=[expression],
-        kwargs={'characters': characters},
+        kwargs={
+            'characters': characters,
+            'keep_empty': keep_empty,
+            'maxsplit': maxsplit,
+            'null_mode': null_mode,
+        },
         is_elementwise=True,
     )
 
-def to_sentence_case(expression: IntoExprColumn) -> pl.Expr:
+def split_by_regex(expression: IntoExprColumn,
+                   pattern: str,
+                   null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='split_by_regex',
+        args=[expression],
+        kwargs={
+            'pattern': pattern,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def to_sentence_case(expression: IntoExprColumn,
+                     null_mode: str = 'propagate') -> pl.Expr:
     return register_plugin_function(
         plugin_path=LIB,
         function_name='to_sentence_case',
         args=[expression],
+        kwargs={'null_mode': null_mode},
         is_elementwise=True,
     )
 
-def to_sponge_case(expression: IntoExprColumn) -> pl.Expr:
+def to_sponge_case(expression: IntoExprColumn,
+                   seed: int | None = None,
+                   upper_probability: float = 0.5,
+                   null_mode: str = 'propagate') -> pl.Expr:
     return register_plugin_function(
         plugin_path=LIB,
         function_name='to_sponge_case',
         args=[expression],
+        kwargs={
+            'seed': seed,
+            'upper_probability': upper_probability,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def to_camel_case(expression: IntoExprColumn,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='to_camel_case',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def to_pascal_case(expression: IntoExprColumn,
+                   null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='to_pascal_case',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def to_snake_case(expression: IntoExprColumn,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='to_snake_case',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def to_kebab_case(expression: IntoExprColumn,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='to_kebab_case',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def to_constant_case(expression: IntoExprColumn,
+                     null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='to_constant_case',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def to_title_case(expression: IntoExprColumn,
+                  stop_words: list[str] | None = None,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='to_title_case',
+        args=[expression],
+        kwargs={
+            **({'stop_words': stop_words} if stop_words is not None else {}),
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def to_swap_case(expression: IntoExprColumn,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='to_swap_case',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def normalize_nfc(expression: IntoExprColumn,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='normalize_nfc',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def normalize_nfd(expression: IntoExprColumn,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='normalize_nfd',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def normalize_nfkc(expression: IntoExprColumn,
+                   null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='normalize_nfkc',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def normalize_nfkd(expression: IntoExprColumn,
+                   null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='normalize_nfkd',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def remove_diacritics(expression: IntoExprColumn,
+                      null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='remove_diacritics',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def slugify(expression: IntoExprColumn,
+            separator: str = '-',
+            lowercase: bool = True,
+            max_length: int | None = None,
+            null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='slugify',
+        args=[expression],
+        kwargs={
+            'separator': separator,
+            'lowercase': lowercase,
+            'max_length': max_length,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def base64_encode(expression: IntoExprColumn,
+                  url_safe: bool = False,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='base64_encode',
+        args=[expression],
+        kwargs={
+            'url_safe': url_safe,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def base64_decode(expression: IntoExprColumn,
+                  url_safe: bool = False,
+                  strict: bool = True,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='base64_decode',
+        args=[expression],
+        kwargs={
+            'url_safe': url_safe,
+            'strict': strict,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def hex_encode(expression: IntoExprColumn,
+               uppercase: bool = False,
+               null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='hex_encode',
+        args=[expression],
+        kwargs={
+            'uppercase': uppercase,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def hex_decode(expression: IntoExprColumn,
+               null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='hex_decode',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def url_encode(expression: IntoExprColumn,
+               encode_spaces_as_plus: bool = False,
+               null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='url_encode',
+        args=[expression],
+        kwargs={
+            'encode_spaces_as_plus': encode_spaces_as_plus,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def url_decode(expression: IntoExprColumn,
+               encode_spaces_as_plus: bool = False,
+               null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='url_decode',
+        args=[expression],
+        kwargs={
+            'encode_spaces_as_plus': encode_spaces_as_plus,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def html_escape(expression: IntoExprColumn,
+                null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='html_escape',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def html_unescape(expression: IntoExprColumn,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='html_unescape',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def replace_regex(expression: IntoExprColumn,
+                  pattern: str,
+                  replacement: str,
+                  case_insensitive: bool = False,
+                  replace_all: bool = True,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='replace_regex',
+        args=[expression],
+        kwargs={
+            'pattern': pattern,
+            'replacement': replacement,
+            'case_insensitive': case_insensitive,
+            'replace_all': replace_all,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def extract_regex(expression: IntoExprColumn,
+                  pattern: str,
+                  group: int = 1,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='extract_regex',
+        args=[expression],
+        kwargs={
+            'pattern': pattern,
+            'group': group,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def extract_all_regex(expression: IntoExprColumn,
+                      pattern: str,
+                      group: int = 0,
+                      null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='extract_all_regex',
+        args=[expression],
+        kwargs={
+            'pattern': pattern,
+            'group': group,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def pad_start(expression: IntoExprColumn,
+              length: int,
+              fill: str = ' ',
+              null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='pad_start',
+        args=[expression],
+        kwargs={
+            'length': length,
+            'fill': fill,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def pad_end(expression: IntoExprColumn,
+            length: int,
+            fill: str = ' ',
+            null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='pad_end',
+        args=[expression],
+        kwargs={
+            'length': length,
+            'fill': fill,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def center(expression: IntoExprColumn,
+           length: int,
+           fill: str = ' ',
+           null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='center',
+        args=[expression],
+        kwargs={
+            'length': length,
+            'fill': fill,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def trim_chars(expression: IntoExprColumn,
+               characters: str = '',
+               null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='trim_chars',
+        args=[expression],
+        kwargs={
+            'characters': characters,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def ltrim_chars(expression: IntoExprColumn,
+                characters: str = '',
+                null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='ltrim_chars',
+        args=[expression],
+        kwargs={
+            'characters': characters,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def rtrim_chars(expression: IntoExprColumn,
+                characters: str = '',
+                null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='rtrim_chars',
+        args=[expression],
+        kwargs={
+            'characters': characters,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def squeeze_whitespace(expression: IntoExprColumn,
+                       keep_char: str = '',
+                       null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='squeeze_whitespace',
+        args=[expression],
+        kwargs={
+            'keep_char': keep_char,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def reverse_string(expression: IntoExprColumn,
+                   null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='reverse_string',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def word_count(expression: IntoExprColumn,
+               pattern: str | None = None,
+               null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='word_count',
+        args=[expression],
+        kwargs={
+            'pattern': pattern,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def grapheme_len(expression: IntoExprColumn,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='grapheme_len',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def truncate_ellipsis(expression: IntoExprColumn,
+                      length: int,
+                      ellipsis: str = '…',
+                      word_boundary: bool = False,
+                      null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='truncate_ellipsis',
+        args=[expression],
+        kwargs={
+            'length': length,
+            'ellipsis': ellipsis,
+            'word_boundary': word_boundary,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def wrap_text(expression: IntoExprColumn,
+              width: int,
+              break_long_words: bool = True,
+              null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='wrap_text',
+        args=[expression],
+        kwargs={
+            'width': width,
+            'break_long_words': break_long_words,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def levenshtein(expression: IntoExprColumn,
+                other: IntoExprColumn,
+                null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='levenshtein',
+        args=[expression, other],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def jaro_winkler(expression: IntoExprColumn,
+                 other: IntoExprColumn,
+                 prefix_weight: float = 0.1,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='jaro_winkler',
+        args=[expression, other],
+        kwargs={
+            'prefix_weight': prefix_weight,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def hamming_distance(expression: IntoExprColumn,
+                     other: IntoExprColumn,
+                     on_length_mismatch: str = 'error',
+                     fill: str = ' ',
+                     null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='hamming_distance',
+        args=[expression, other],
+        kwargs={
+            'on_length_mismatch': on_length_mismatch,
+            'fill': fill,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def soundex(expression: IntoExprColumn,
+            null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='soundex',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def metaphone(expression: IntoExprColumn,
+              max_length: int = 4,
+              null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='metaphone',
+        args=[expression],
+        kwargs={
+            'max_length': max_length,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def ngrams(expression: IntoExprColumn,
+           n: int,
+           char_level: bool = False,
+           separator: str = ' ',
+           null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='ngrams',
+        args=[expression],
+        kwargs={
+            'n': n,
+            'char_level': char_level,
+            'separator': separator,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def count_substring(expression: IntoExprColumn,
+                    needle: str,
+                    overlapping: bool = False,
+                    case_insensitive: bool = False,
+                    null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='count_substring',
+        args=[expression],
+        kwargs={
+            'needle': needle,
+            'overlapping': overlapping,
+            'case_insensitive': case_insensitive,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def find_all_positions(expression: IntoExprColumn,
+                       needle: str,
+                       case_insensitive: bool = False,
+                       null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='find_all_positions',
+        args=[expression],
+        kwargs={
+            'needle': needle,
+            'case_insensitive': case_insensitive,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def mask(expression: IntoExprColumn,
+         keep_start: int = 0,
+         keep_end: int = 0,
+         mask_char: str = '*',
+         null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='mask',
+        args=[expression],
+        kwargs={
+            'keep_start': keep_start,
+            'keep_end': keep_end,
+            'mask_char': mask_char,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def mask_email(expression: IntoExprColumn,
+               reveal_first: int = 1,
+               mask_invalid: bool = False,
+               null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='mask_email',
+        args=[expression],
+        kwargs={
+            'reveal_first': reveal_first,
+            'mask_invalid': mask_invalid,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def extract_emails(expression: IntoExprColumn,
+                   dedupe: bool = False,
+                   null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='extract_emails',
+        args=[expression],
+        kwargs={
+            'dedupe': dedupe,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def extract_urls(expression: IntoExprColumn,
+                 schemes: list[str] | None = None,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='extract_urls',
+        args=[expression],
+        kwargs={
+            **({'schemes': schemes} if schemes is not None else {}),
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def extract_numbers(expression: IntoExprColumn,
+                    allow_thousands_separator: bool = False,
+                    null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='extract_numbers',
+        args=[expression],
+        kwargs={
+            'allow_thousands_separator': allow_thousands_separator,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def number_to_words(expression: IntoExprColumn,
+                    use_and: bool = False,
+                    null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='number_to_words',
+        args=[expression],
+        kwargs={
+            'use_and': use_and,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def ordinalize(expression: IntoExprColumn,
+               null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='ordinalize',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def roman_encode(expression: IntoExprColumn,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='roman_encode',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def roman_decode(expression: IntoExprColumn,
+                 strict: bool = True,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='roman_decode',
+        args=[expression],
+        kwargs={
+            'strict': strict,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def repeat(expression: IntoExprColumn,
+           count: int,
+           separator: str = '',
+           null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='repeat',
+        args=[expression],
+        kwargs={
+            'count': count,
+            'separator': separator,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def strip_html(expression: IntoExprColumn,
+               drop_script_style: bool = True,
+               null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='strip_html',
+        args=[expression],
+        kwargs={
+            'drop_script_style': drop_script_style,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def remove_emoji(expression: IntoExprColumn,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='remove_emoji',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def extract_emoji(expression: IntoExprColumn,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='extract_emoji',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def strip_zero_width(expression: IntoExprColumn,
+                     strip_directional: bool = False,
+                     null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='strip_zero_width',
+        args=[expression],
+        kwargs={
+            'strip_directional': strip_directional,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def rot_n(expression: IntoExprColumn,
+          shift: int = 13,
+          null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='rot_n',
+        args=[expression],
+        kwargs={
+            'shift': shift,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def is_palindrome(expression: IntoExprColumn,
+                  ignore_case: bool = False,
+                  ignore_non_alnum: bool = False,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='is_palindrome',
+        args=[expression],
+        kwargs={
+            'ignore_case': ignore_case,
+            'ignore_non_alnum': ignore_non_alnum,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def initials(expression: IntoExprColumn,
+             uppercase: bool = True,
+             separator: str = '',
+             max_count: int | None = None,
+             null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='initials',
+        args=[expression],
+        kwargs={
+            'uppercase': uppercase,
+            'separator': separator,
+            'max_count': max_count,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def to_ascii(expression: IntoExprColumn,
+             replacement: str = '?',
+             null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='to_ascii',
+        args=[expression],
+        kwargs={
+            'replacement': replacement,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def longest_common_prefix(expression: IntoExprColumn,
+                          null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='longest_common_prefix',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=False,
+        returns_scalar=True,
+    )
+
+def remove_suffix(expression: IntoExprColumn,
+                  suffix: str,
+                  case_insensitive: bool = False,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='remove_suffix',
+        args=[expression],
+        kwargs={
+            'suffix': suffix,
+            'case_insensitive': case_insensitive,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def remove_prefix(expression: IntoExprColumn,
+                  prefix: str,
+                  case_insensitive: bool = False,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='remove_prefix',
+        args=[expression],
+        kwargs={
+            'prefix': prefix,
+            'case_insensitive': case_insensitive,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def detect_case(expression: IntoExprColumn,
+                null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='detect_case',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def caseless_equals(expression: IntoExprColumn,
+                    other: IntoExprColumn,
+                    null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='caseless_equals',
+        args=[expression, other],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def similarity_ratio(expression: IntoExprColumn,
+                     other: IntoExprColumn,
+                     null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='similarity_ratio',
+        args=[expression, other],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def tokenize(expression: IntoExprColumn,
+             pattern: str = r'\W+',
+             lowercase: bool = True,
+             min_token_len: int = 1,
+             drop_stopwords: bool = False,
+             null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='tokenize',
+        args=[expression],
+        kwargs={
+            'pattern': pattern,
+            'lowercase': lowercase,
+            'min_token_len': min_token_len,
+            'drop_stopwords': drop_stopwords,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def morse_encode(expression: IntoExprColumn,
+                 letter_sep: str = ' ',
+                 word_sep: str = ' / ',
+                 skip_unsupported: bool = False,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='morse_encode',
+        args=[expression],
+        kwargs={
+            'letter_sep': letter_sep,
+            'word_sep': word_sep,
+            'skip_unsupported': skip_unsupported,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def morse_decode(expression: IntoExprColumn,
+                 letter_sep: str = ' ',
+                 word_sep: str = ' / ',
+                 skip_unsupported: bool = False,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='morse_decode',
+        args=[expression],
+        kwargs={
+            'letter_sep': letter_sep,
+            'word_sep': word_sep,
+            'skip_unsupported': skip_unsupported,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def to_leetspeak(expression: IntoExprColumn,
+                 level: int = 1,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='to_leetspeak',
+        args=[expression],
+        kwargs={
+            'level': level,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def to_fullwidth(expression: IntoExprColumn,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='to_fullwidth',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def to_halfwidth(expression: IntoExprColumn,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='to_halfwidth',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def expand_contractions(expression: IntoExprColumn,
+                        extra: list[tuple[str, str]] | None = None,
+                        null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='expand_contractions',
+        args=[expression],
+        kwargs={
+            **({'extra': extra} if extra is not None else {}),
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def normalize_quotes(expression: IntoExprColumn,
+                     to_straight: bool = True,
+                     dashes: bool = False,
+                     null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='normalize_quotes',
+        args=[expression],
+        kwargs={
+            'to_straight': to_straight,
+            'dashes': dashes,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def char_stats(expression: IntoExprColumn,
+               null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='char_stats',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def count_vowels(expression: IntoExprColumn,
+                 treat_y_as_vowel: bool = False,
+                 ascii_only: bool = False,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='count_vowels',
+        args=[expression],
+        kwargs={
+            'treat_y_as_vowel': treat_y_as_vowel,
+            'ascii_only': ascii_only,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def count_consonants(expression: IntoExprColumn,
+                     treat_y_as_vowel: bool = False,
+                     ascii_only: bool = False,
+                     null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='count_consonants',
+        args=[expression],
+        kwargs={
+            'treat_y_as_vowel': treat_y_as_vowel,
+            'ascii_only': ascii_only,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def syllable_count(expression: IntoExprColumn,
+                   per_word: bool = False,
+                   null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='syllable_count',
+        args=[expression],
+        kwargs={
+            'per_word': per_word,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def flesch_reading_ease(expression: IntoExprColumn,
+                        null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='flesch_reading_ease',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def detect_script(expression: IntoExprColumn,
+                  threshold: float = 0.6,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='detect_script',
+        args=[expression],
+        kwargs={
+            'threshold': threshold,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def redact_pii(expression: IntoExprColumn,
+               redact_emails: bool = True,
+               redact_phones: bool = True,
+               redact_ssn: bool = True,
+               mask_char: str = '*',
+               null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='redact_pii',
+        args=[expression],
+        kwargs={
+            'redact_emails': redact_emails,
+            'redact_phones': redact_phones,
+            'redact_ssn': redact_ssn,
+            'mask_char': mask_char,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def fuzzy_contains(expression: IntoExprColumn,
+                   needle: str,
+                   max_distance: int = 1,
+                   case_insensitive: bool = False,
+                   null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='fuzzy_contains',
+        args=[expression],
+        kwargs={
+            'needle': needle,
+            'max_distance': max_distance,
+            'case_insensitive': case_insensitive,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def split_words(expression: IntoExprColumn,
+                null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='split_words',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def search_key(expression: IntoExprColumn,
+               normalize: bool = True,
+               remove_diacritics: bool = True,
+               case_fold: bool = True,
+               squeeze_whitespace: bool = True,
+               null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='search_key',
+        args=[expression],
+        kwargs={
+            'normalize': normalize,
+            'remove_diacritics': remove_diacritics,
+            'case_fold': case_fold,
+            'squeeze_whitespace': squeeze_whitespace,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def pluralize(expression: IntoExprColumn,
+              extra_irregulars: list[tuple[str, str]] | None = None,
+              null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='pluralize',
+        args=[expression],
+        kwargs={
+            **({'extra_irregulars': extra_irregulars} if extra_irregulars is not None else {}),
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def singularize(expression: IntoExprColumn,
+                extra_irregulars: list[tuple[str, str]] | None = None,
+                null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='singularize',
+        args=[expression],
+        kwargs={
+            **({'extra_irregulars': extra_irregulars} if extra_irregulars is not None else {}),
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def to_name_case(expression: IntoExprColumn,
+                 mc_rule: bool = True,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='to_name_case',
+        args=[expression],
+        kwargs={
+            'mc_rule': mc_rule,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def to_alternating_case(expression: IntoExprColumn,
+                        start_upper: bool = False,
+                        skip_nonalpha: bool = False,
+                        null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='to_alternating_case',
+        args=[expression],
+        kwargs={
+            'start_upper': start_upper,
+            'skip_nonalpha': skip_nonalpha,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def strip_accents(expression: IntoExprColumn,
+                  preserve: list[str] | None = None,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='strip_accents',
+        args=[expression],
+        kwargs={
+            **({'preserve': preserve} if preserve is not None else {}),
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def fit_width(expression: IntoExprColumn,
+              width: int,
+              align: str = 'left',
+              fill: str = ' ',
+              truncate: bool = False,
+              null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='fit_width',
+        args=[expression],
+        kwargs={
+            'width': width,
+            'align': align,
+            'fill': fill,
+            'truncate': truncate,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def top_keywords(expression: IntoExprColumn,
+                 n: int = 5,
+                 stopwords: list[str] | None = None,
+                 min_len: int = 3,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='top_keywords',
+        args=[expression],
+        kwargs={
+            'n': n,
+            **({'stopwords': stopwords} if stopwords is not None else {}),
+            'min_len': min_len,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def normalize_phone(expression: IntoExprColumn,
+                    default_country_code: str = '1',
+                    output_format: str = 'e164',
+                    strict: bool = False,
+                    null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='normalize_phone',
+        args=[expression],
+        kwargs={
+            'default_country_code': default_country_code,
+            'output_format': output_format,
+            'strict': strict,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def parse_bool(expression: IntoExprColumn,
+               strict: bool = False,
+               null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='parse_bool',
+        args=[expression],
+        kwargs={
+            'strict': strict,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def hash_string(expression: IntoExprColumn,
+                algorithm: str = 'sha256',
+                output: str = 'hex',
+                null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='hash_string',
+        args=[expression],
+        kwargs={
+            'algorithm': algorithm,
+            'output': output,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def base32_encode(expression: IntoExprColumn,
+                  padding: bool = True,
+                  lowercase: bool = False,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='base32_encode',
+        args=[expression],
+        kwargs={
+            'padding': padding,
+            'lowercase': lowercase,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def base32_decode(expression: IntoExprColumn,
+                  strict: bool = True,
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='base32_decode',
+        args=[expression],
+        kwargs={
+            'strict': strict,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def fix_mojibake(expression: IntoExprColumn,
+                 aggressive: bool = False,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='fix_mojibake',
+        args=[expression],
+        kwargs={
+            'aggressive': aggressive,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def split_fixed(expression: IntoExprColumn,
+                separator: str,
+                n: int,
+                fill: str | None = None,
+                null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='split_fixed',
+        args=[expression],
+        kwargs={
+            'separator': separator,
+            'n': n,
+            'fill': fill,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def chunk(expression: IntoExprColumn,
+          size: int,
+          grapheme: bool = False,
+          null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='chunk',
+        args=[expression],
+        kwargs={
+            'size': size,
+            'grapheme': grapheme,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def group_digits(expression: IntoExprColumn,
+                 group_size: int = 3,
+                 group_sep: str = ',',
+                 decimal_sep: str = '.',
+                 strict: bool = False,
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='group_digits',
+        args=[expression],
+        kwargs={
+            'group_size': group_size,
+            'group_sep': group_sep,
+            'decimal_sep': decimal_sep,
+            'strict': strict,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def dedupe_tokens(expression: IntoExprColumn,
+                  separator: str = ',',
+                  keep: str = 'first',
+                  null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='dedupe_tokens',
+        args=[expression],
+        kwargs={
+            'separator': separator,
+            'keep': keep,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def sort_tokens(expression: IntoExprColumn,
+                separator: str = ',',
+                descending: bool = False,
+                case_insensitive: bool = False,
+                numeric: bool = False,
+                null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='sort_tokens',
+        args=[expression],
+        kwargs={
+            'separator': separator,
+            'descending': descending,
+            'case_insensitive': case_insensitive,
+            'numeric': numeric,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def anagram_key(expression: IntoExprColumn,
+                ignore_case: bool = True,
+                ignore_non_letters: bool = True,
+                null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='anagram_key',
+        args=[expression],
+        kwargs={
+            'ignore_case': ignore_case,
+            'ignore_non_letters': ignore_non_letters,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def guess_encoding(expression: IntoExprColumn,
+                   null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='guess_encoding',
+        args=[expression],
+        kwargs={'null_mode': null_mode},
+        is_elementwise=True,
+    )
+
+def decode_bytes(expression: IntoExprColumn,
+                 encoding: str,
+                 errors: str = 'strict',
+                 null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='decode_bytes',
+        args=[expression],
+        kwargs={
+            'encoding': encoding,
+            'errors': errors,
+            'null_mode': null_mode,
+        },
+        is_elementwise=True,
+    )
+
+def best_match(expression: IntoExprColumn,
+               candidates: list[str],
+               metric: str = 'jaro_winkler',
+               threshold: float = 0.8,
+               null_mode: str = 'propagate') -> pl.Expr:
+    return register_plugin_function(
+        plugin_path=LIB,
+        function_name='best_match',
+        args=[expression],
+        kwargs={
+            'candidates': candidates,
+            'metric': metric,
+            'threshold': threshold,
+            'null_mode': null_mode,
+        },
         is_elementwise=True,
     )
\ No newline at end of file
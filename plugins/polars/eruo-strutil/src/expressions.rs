@@ -1,119 +1,377 @@
 #![allow(clippy::unused_unit)]
+use base64::alphabet;
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::Engine;
+use encoding_rs::{DecoderResult, Encoding, REPLACEMENT};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use polars::prelude::*;
+use polars_arrow::array::ValueSize;
+use polars_arrow::bitmap::Bitmap;
 use pyo3_polars::derive::polars_expr;
-use rand::Rng;
+use pyo3_polars::export::polars_core::utils::_split_offsets;
+use pyo3_polars::export::polars_core::POOL;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rayon::prelude::*;
+use regex::{Regex, RegexBuilder};
 use serde::Deserialize;
-use std::fmt::Write;
+use sha2::{Digest, Sha256};
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+use xxhash_rust::xxh3::xxh3_64;
 
 fn is_vowel(c: char) -> bool {
     matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u')
 }
 
-fn pig_latin_word(word: &str) -> String {
+// The ASCII letter a character is built on, so 'é' gives 'e'; other letters, such as 'ß' or Han, give None
+fn latin_base_letter(c: char, ascii_only: bool) -> Option<char> {
+    let base = if ascii_only { c } else { std::iter::once(c).nfd().next().unwrap_or(c) };
+    base.is_ascii_alphabetic().then_some(base)
+}
+
+fn is_vowel_letter(c: char, treat_y_as_vowel: bool, ascii_only: bool) -> bool {
+    latin_base_letter(c, ascii_only)
+        .is_some_and(|base| is_vowel(base) || (treat_y_as_vowel && base.eq_ignore_ascii_case(&'y')))
+}
+
+fn is_consonant_letter(c: char, treat_y_as_vowel: bool, ascii_only: bool) -> bool {
+    latin_base_letter(c, ascii_only).is_some() && !is_vowel_letter(c, treat_y_as_vowel, ascii_only)
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct PigLatinKwargs {
+    vowel_suffix: String,
+    consonant_suffix: String,
+    treat_y_as_vowel: bool,
+    null_mode: NullMode,
+}
+
+impl Default for PigLatinKwargs {
+    fn default() -> Self {
+        PigLatinKwargs {
+            vowel_suffix: "way".to_string(),
+            consonant_suffix: "ay".to_string(),
+            treat_y_as_vowel: false,
+            null_mode: NullMode::Propagate,
+        }
+    }
+}
+
+fn pig_latin_word(word: &str, kwargs: &PigLatinKwargs) -> String {
     if word.is_empty() {
         return String::new();
     }
 
-    // Handle punctuation
-    let (word_content, punctuation) =
-    if let Some(last_char) = word.chars().last() {
-        if last_char.is_ascii_punctuation() {
-            (&word[0..word.len() - 1], Some(last_char))
-        } else {
-            (word, None)
-        }
-    } else {
-        (word, None)
+    // Handle punctuation, slicing at the byte offset of the last character
+    // so multibyte characters are never split
+    let (word_content, punctuation) = match word.char_indices().last() {
+        Some((i, c)) if !c.is_alphanumeric() => (&word[..i], &word[i..]),
+        _ => (word, ""),
     };
 
-    // Find the end of the initial consonant cluster
-    let mut consonant_cluster_end = 0;
-    if !is_vowel(word_content.chars().next().unwrap()) {
-        for (i, c) in word_content.chars().enumerate() {
-            if is_vowel(c) {
-                consonant_cluster_end = i;
-                break;
-            }
-            // If the word has no vowels, treat it as a special case
-            if i == word_content.len() - 1 {
-                consonant_cluster_end = word_content.len();
-            }
-        }
+    // Leave words without any letter, such as lone punctuation, untouched
+    if !word_content.chars().any(char::is_alphabetic) {
+        return word.to_string();
     }
 
+    // Find the byte offset of the end of the initial consonant cluster. If the
+    // word has no vowels, treat it as a special case and move the whole word.
+    // Only a leading 'y' can count as a vowel, as in "yellow".
+    let consonant_cluster_end = word_content
+        .char_indices()
+        .find(|&(i, c)| is_vowel(c) || (kwargs.treat_y_as_vowel && i == 0 && c.eq_ignore_ascii_case(&'y')))
+        .map_or(word_content.len(), |(i, _)| i);
+
     let result =
     if consonant_cluster_end == 0 {
-        // Vowel starts the word, so just add "way"
-        format!("{}way", word_content)
+        // Vowel starts the word, so just add the vowel suffix, e.g. "way"
+        format!("{}{}", word_content, kwargs.vowel_suffix)
     } else {
-        // Consonant cluster is moved to the end with "ay"
+        // Consonant cluster is moved to the end with the consonant suffix, e.g. "ay"
         let (consonant_cluster, rest_of_word) = word_content.split_at(consonant_cluster_end);
 
-        let mut pig_latin_word_content = format!("{}{}{}", rest_of_word, consonant_cluster, "ay");
+        let mut pig_latin_word_content = format!("{}{}{}", rest_of_word, consonant_cluster, kwargs.consonant_suffix);
 
         // Handle capitalization
-        if word_content.chars().next().unwrap().is_ascii_uppercase() {
-            let first_char = pig_latin_word_content.chars().next().unwrap().to_ascii_uppercase();
-            pig_latin_word_content.replace_range(..1, &first_char.to_string());
+        if word_content.starts_with(char::is_uppercase) {
+            if let Some(first_char) = pig_latin_word_content.chars().next() {
+                let first_char_upper: String = first_char.to_uppercase().collect();
+                pig_latin_word_content.replace_range(..first_char.len_utf8(), &first_char_upper);
+            }
         }
 
         pig_latin_word_content
     };
 
     // Add back punctuation if it existed
-    if let Some(punc) = punctuation {
-        format!("{}{}", result, punc)
-    } else {
-        result
-    }
+    format!("{}{}", result, punctuation)
 }
 
 #[polars_expr(output_type=String)]
-fn pig_latinnify(inputs: &[Series]) -> PolarsResult<Series> {
-    let ca: &StringChunked = inputs[0].str()?;
-    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+fn pig_latinnify(inputs: &[Series], kwargs: PigLatinKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
         let translated_words: Vec<String> = value
             .split_whitespace()
-            .map(|word| pig_latin_word(word))
+            .map(|word| pig_latin_word(word, &kwargs))
             .collect();
-        write!(output, "{}", translated_words.join(" ")).unwrap();
-    });
+        output.push_str(&translated_words.join(" "));
+    })?;
     Ok(out.into_series())
 }
 
+fn default_true() -> bool {
+    true
+}
+
 #[derive(Deserialize)]
 pub struct SplitByCharsKwargs {
     characters: String,
+    #[serde(default = "default_true")]
+    keep_empty: bool,
+    #[serde(default)]
+    maxsplit: Option<usize>,
+    #[serde(default)]
+    null_mode: NullMode,
 }
 
-#[polars_expr(output_type=String)]
+fn split_by_chars_parts<'a>(value: &'a str, kwargs: &SplitByCharsKwargs) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    let mut rest = value;
+
+    loop {
+        // Keep the remainder intact once the maximum number of splits is honored.
+        // Dropped empty parts do not count as a split.
+        if kwargs.maxsplit.is_some_and(|maxsplit| parts.len() >= maxsplit) {
+            break;
+        }
+
+        let Some((i, c)) = rest.char_indices().find(|&(_, c)| kwargs.characters.contains(c)) else {
+            break;
+        };
+
+        let part = rest[..i].trim();
+        if kwargs.keep_empty || !part.is_empty() {
+            parts.push(part);
+        }
+        rest = &rest[i + c.len_utf8()..];
+    }
+
+    // Without empty parts, the remainder must not start with an empty part either
+    if !kwargs.keep_empty {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || kwargs.characters.contains(c));
+    }
+
+    let part = rest.trim();
+    if kwargs.keep_empty || !part.is_empty() {
+        parts.push(part);
+    }
+
+    parts
+}
+
+fn list_string_output(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    Ok(Field::new(field.name().clone(), DataType::List(Box::new(DataType::String))))
+}
+
+fn list_uint32_output(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    Ok(Field::new(field.name().clone(), DataType::List(Box::new(DataType::UInt32))))
+}
+
+fn list_float64_output(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    Ok(Field::new(field.name().clone(), DataType::List(Box::new(DataType::Float64))))
+}
+
+// How every expression treats null input rows. Nulls produced by an expression itself, such as
+// a regex without a match, are not affected.
+#[derive(Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum NullMode {
+    // Null rows stay null
+    #[default]
+    Propagate,
+    // Null rows are processed as empty strings
+    Empty,
+    // Any null row fails the expression
+    Error,
+}
+
+#[derive(Deserialize)]
+pub struct NullModeKwargs {
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn ensure_no_nulls(null_count: usize, null_mode: NullMode) -> PolarsResult<()> {
+    polars_ensure!(
+        null_mode != NullMode::Error || null_count == 0,
+        ComputeError: "input contains {} null value(s) but null_mode is 'error'", null_count
+    );
+    Ok(())
+}
+
+fn fill_null_with_empty(ca: &StringChunked) -> StringChunked {
+    let out: StringChunked = ca.iter().map(|value| Some(value.unwrap_or(""))).collect();
+    out.with_name(ca.name().clone())
+}
+
+fn apply_null_mode(ca: &StringChunked, null_mode: NullMode) -> PolarsResult<StringChunked> {
+    ensure_no_nulls(ca.null_count(), null_mode)?;
+    if null_mode == NullMode::Empty && ca.null_count() > 0 {
+        return Ok(fill_null_with_empty(ca));
+    }
+    Ok(ca.clone())
+}
+
+// Integer inputs have no empty value, so under "empty" their null rows become empty strings
+fn fill_null_output(out: StringChunked, null_mode: NullMode) -> StringChunked {
+    match null_mode {
+        NullMode::Empty => fill_null_with_empty(&out),
+        _ => out,
+    }
+}
+
+// Below this many rows, splitting the work costs more than it saves
+const PARALLEL_MIN_ROWS: usize = 1 << 14;
+
+// Map contiguous row ranges on the Polars thread pool and stitch the results back in row order.
+// The transform must be row-independent; small inputs run on the calling thread.
+fn par_map_ranges<F>(len: usize, f: F) -> PolarsResult<Series>
+where
+    F: Fn(i64, usize) -> PolarsResult<Series> + Sync,
+{
+    let n_threads = POOL.current_num_threads();
+    if n_threads == 1 || len < PARALLEL_MIN_ROWS {
+        return f(0, len);
+    }
+    let slices = POOL.install(|| {
+        _split_offsets(len, n_threads)
+            .into_par_iter()
+            .map(|(offset, len)| f(offset as i64, len))
+            .collect::<PolarsResult<Vec<Series>>>()
+    })?;
+    let mut out = f(0, 0)?;
+    for slice in &slices {
+        out.append(slice)?;
+    }
+    Ok(out)
+}
+
+fn par_map_slices<T, F>(ca: &ChunkedArray<T>, f: F) -> PolarsResult<Series>
+where
+    T: PolarsDataType,
+    F: Fn(&ChunkedArray<T>) -> PolarsResult<Series> + Sync,
+{
+    par_map_ranges(ca.len(), |offset, len| f(&ca.slice(offset, len)))
+}
+
+// Both sides are sliced at the same rows, so they must have the same length
+fn par_map_slice_pairs<F>(left: &StringChunked, right: &StringChunked, f: F) -> PolarsResult<Series>
+where
+    F: Fn(&StringChunked, &StringChunked) -> PolarsResult<Series> + Sync,
+{
+    par_map_ranges(left.len(), |offset, len| f(&left.slice(offset, len), &right.slice(offset, len)))
+}
+
+fn par_apply_into_string<F>(ca: &StringChunked, f: F) -> PolarsResult<StringChunked>
+where
+    F: Fn(&str, &mut String) + Sync,
+{
+    let out = par_map_slices(ca, |ca| Ok(ca.apply_into_string_amortized(&f).into_series()))?;
+    Ok(out.str()?.clone())
+}
+
+fn par_try_apply_into_string<F>(ca: &StringChunked, f: F) -> PolarsResult<StringChunked>
+where
+    F: Fn(&str, &mut String) -> PolarsResult<()> + Sync,
+{
+    let out = par_map_slices(ca, |ca| Ok(ca.try_apply_into_string_amortized(&f)?.into_series()))?;
+    Ok(out.str()?.clone())
+}
+
+fn par_apply_nonnull_values_generic<U, K, F>(ca: &StringChunked, dtype: DataType, f: F) -> PolarsResult<ChunkedArray<U>>
+where
+    U: PolarsPhysicalType,
+    U::Array: ArrayFromIterDtype<K> + ArrayFromIterDtype<Option<K>>,
+    F: Fn(&str) -> K + Sync,
+{
+    let out = par_map_slices(ca, |ca| Ok(ca.apply_nonnull_values_generic::<U, K, _>(dtype.clone(), &f).into_series()))?;
+    Ok(out.unpack::<U>()?.clone())
+}
+
+#[polars_expr(output_type_func=list_string_output)]
 fn split_by_chars(inputs: &[Series], kwargs: SplitByCharsKwargs) -> PolarsResult<Series> {
-    let ca: &StringChunked = inputs[0].str()?;
-    let SplitByCharsKwargs { characters } = kwargs;
-    let mut all_results: Vec<String> = Vec::new();
-    for value in ca.iter() {
-        if let Some(s) = value {
-            for part in s.split(|c: char| characters.contains(c)) {
-                all_results.push(part.trim().to_string());
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    par_map_slices(ca, |ca| {
+        let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.get_values_size());
+        for value in ca.iter() {
+            match value {
+                // Each row maps to a list of its trimmed parts
+                Some(s) => builder.append_values_iter(split_by_chars_parts(s, &kwargs).into_iter()),
+                // Null rows stay null rather than becoming empty lists
+                None => builder.append_null(),
             }
         }
-    }
-    let out: StringChunked = all_results.iter().map(|s| Some(s.as_str())).collect::<StringChunked>();
-    Ok(out.into_series())
+        Ok(builder.finish().into_series())
+    })
+}
+
+#[derive(Deserialize)]
+pub struct SplitByRegexKwargs {
+    pattern: String,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn compile_regex(pattern: &str, case_insensitive: bool) -> PolarsResult<Regex> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(case_insensitive)
+        .build()
+        .map_err(|e| polars_err!(ComputeError: "invalid regex pattern '{}': {}", pattern, e))
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn split_by_regex(inputs: &[Series], kwargs: SplitByRegexKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    // Compile once up front rather than for every row
+    let regex = compile_regex(&kwargs.pattern, false)?;
+    par_map_slices(ca, |ca| {
+        let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.get_values_size());
+        for value in ca.iter() {
+            match value {
+                Some(s) => builder.append_values_iter(regex.split(s)),
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into_series())
+    })
+}
+
+fn is_case_hump(prev: char, c: char) -> bool {
+    prev.is_lowercase() && c.is_uppercase()
+}
+
+fn is_sentence_ender(c: char) -> bool {
+    matches!(c, '.' | '!' | '?')
 }
 
 #[polars_expr(output_type=String)]
-fn to_sentence_case(inputs: &[Series]) -> PolarsResult<Series> {
-    let ca: &StringChunked = inputs[0].str()?;
-    let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+fn to_sentence_case(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
         let mut capitalize_next = true;
-        let mut last_char_was_lowercase = false;
+        let mut last_letter: Option<char> = None;
         let mut last_char_was_sentence_ender = false;
 
         for c in value.chars() {
             if c.is_alphabetic() {
                 // Insert a space if the last character was lowercase and the current is uppercase.
-                let should_insert_space = last_char_was_lowercase && c.is_uppercase();
+                let should_insert_space = last_letter.is_some_and(|prev| is_case_hump(prev, c));
                 if should_insert_space {
                     output.push(' ');
                 }
@@ -127,14 +385,14 @@ fn to_sentence_case(inputs: &[Series]) -> PolarsResult<Series> {
 
                 // Update state variables for the next iteration.
                 capitalize_next = false;
-                last_char_was_lowercase = c.is_lowercase();
+                last_letter = Some(c);
                 last_char_was_sentence_ender = false;
             }
             // It's a non-alphabetic character.
             else {
                 output.push(c);
 
-                if c == '.' || c == '!' || c == '?' {
+                if is_sentence_ender(c) {
                     last_char_was_sentence_ender = true;
                 }
                 // Capitalize the next letter if the last character was a sentence ender and this is a space.
@@ -147,21 +405,49 @@ fn to_sentence_case(inputs: &[Series]) -> PolarsResult<Series> {
                     capitalize_next = false;
                     last_char_was_sentence_ender = false;
                 }
-                last_char_was_lowercase = false;
+                last_letter = None;
             }
         }
-    });
+    })?;
     Ok(out.into_series())
 }
 
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct SpongeCaseKwargs {
+    seed: Option<u64>,
+    upper_probability: f64,
+    null_mode: NullMode,
+}
+
+impl Default for SpongeCaseKwargs {
+    fn default() -> Self {
+        SpongeCaseKwargs {
+            seed: None,
+            upper_probability: 0.5,
+            null_mode: NullMode::Propagate,
+        }
+    }
+}
+
 #[polars_expr(output_type=String)]
-fn to_sponge_case(inputs: &[Series]) -> PolarsResult<Series> {
-    let ca: &StringChunked = inputs[0].str()?;
-    let mut rng = rand::rng();
+fn to_sponge_case(inputs: &[Series], kwargs: SpongeCaseKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let SpongeCaseKwargs { seed, upper_probability, .. } = kwargs;
+    polars_ensure!(
+        (0.0..=1.0).contains(&upper_probability),
+        ComputeError: "upper_probability must be within [0, 1], got {}", upper_probability
+    );
+    // Seeding makes the output reproducible for identical inputs
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_rng(&mut rand::rng()),
+    };
+    // One generator runs through the rows in order, so this stays off the thread pool
     let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
         for c in value.chars() {
             if c.is_alphabetic() {
-                if rng.random_bool(0.5) {
+                if rng.random_bool(upper_probability) {
                     output.extend(c.to_uppercase());
                 } else {
                     output.extend(c.to_lowercase());
@@ -172,4 +458,4432 @@ fn to_sponge_case(inputs: &[Series]) -> PolarsResult<Series> {
         }
     });
     Ok(out.into_series())
-}
\ No newline at end of file
+}
+
+fn is_word_separator(c: char) -> bool {
+    c.is_whitespace() || c == '-' || c == '_' || c == '.'
+}
+
+// Split into words at separators and case humps, e.g. "XMLHttpRequest" becomes
+// ["XML", "Http", "Request"]. Other punctuation is dropped without breaking the word.
+fn split_into_words(value: &str) -> Vec<String> {
+    let chars: Vec<char> = value
+        .chars()
+        .filter(|&c| c.is_alphanumeric() || is_word_separator(c))
+        .collect();
+
+    let mut words = Vec::new();
+    let mut word = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if is_word_separator(c) {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+
+        if !word.is_empty() {
+            let prev = chars[i - 1];
+            let next = chars.get(i + 1).copied();
+            let is_hump = is_case_hump(prev, c) || (prev.is_numeric() && c.is_uppercase());
+            let is_acronym_end = prev.is_uppercase() && c.is_uppercase() && next.is_some_and(char::is_lowercase);
+            if is_hump || is_acronym_end {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+
+        word.push(c);
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+fn push_capitalized(word: &str, output: &mut String) {
+    let mut chars = word.chars();
+    if let Some(first_char) = chars.next() {
+        output.extend(first_char.to_uppercase());
+        output.extend(chars.flat_map(char::to_lowercase));
+    }
+}
+
+fn push_delimited_words(value: &str, delimiter: char, uppercase: bool, output: &mut String) {
+    for (i, word) in split_into_words(value).iter().enumerate() {
+        if i > 0 {
+            output.push(delimiter);
+        }
+        if uppercase {
+            output.extend(word.chars().flat_map(char::to_uppercase));
+        } else {
+            output.extend(word.chars().flat_map(char::to_lowercase));
+        }
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn to_camel_case(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        for (i, word) in split_into_words(value).iter().enumerate() {
+            if i == 0 {
+                output.extend(word.chars().flat_map(char::to_lowercase));
+            } else {
+                push_capitalized(word, output);
+            }
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn to_pascal_case(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        for word in split_into_words(value) {
+            push_capitalized(&word, output);
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn to_snake_case(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        push_delimited_words(value, '_', false, output);
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn to_kebab_case(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        push_delimited_words(value, '-', false, output);
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn to_constant_case(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        push_delimited_words(value, '_', true, output);
+    })?;
+    Ok(out.into_series())
+}
+
+fn default_title_case_stop_words() -> Vec<String> {
+    [
+        "a", "an", "and", "as", "at", "but", "by", "for", "from", "in", "into", "nor", "of", "on", "or", "over",
+        "per", "so", "the", "to", "up", "via", "vs", "with", "yet",
+    ]
+    .iter()
+    .map(|word| word.to_string())
+    .collect()
+}
+
+#[derive(Deserialize)]
+pub struct TitleCaseKwargs {
+    #[serde(default = "default_title_case_stop_words")]
+    stop_words: Vec<String>,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn to_title_case(inputs: &[Series], kwargs: TitleCaseKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let stop_words: Vec<String> = kwargs.stop_words.iter().map(|word| word.to_lowercase()).collect();
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        let word_count = value.split_whitespace().count();
+        let mut word_index = 0;
+        let mut rest = value;
+
+        while !rest.is_empty() {
+            // Keep the original whitespace as is
+            let word_start = rest.find(|c: char| !c.is_whitespace()).unwrap_or(rest.len());
+            output.push_str(&rest[..word_start]);
+            rest = &rest[word_start..];
+
+            let word_end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            let word = &rest[..word_end];
+            rest = &rest[word_end..];
+            if word.is_empty() {
+                break;
+            }
+
+            // The first and last words are always capitalized
+            let is_first_or_last = word_index == 0 || word_index == word_count - 1;
+            let bare_word = word.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase();
+            word_index += 1;
+
+            if !is_first_or_last && stop_words.contains(&bare_word) {
+                output.extend(word.chars().flat_map(char::to_lowercase));
+                continue;
+            }
+
+            // Capitalize the first letter, leaving any leading punctuation as is
+            let mut capitalized = false;
+            for c in word.chars() {
+                if !capitalized && c.is_alphanumeric() {
+                    output.extend(c.to_uppercase());
+                    capitalized = true;
+                } else {
+                    output.extend(c.to_lowercase());
+                }
+            }
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn to_swap_case(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        for c in value.chars() {
+            // Case mappings may expand to several characters, e.g. "ß" into "SS"
+            if c.is_uppercase() {
+                output.extend(c.to_lowercase());
+            } else if c.is_lowercase() {
+                output.extend(c.to_uppercase());
+            } else {
+                output.push(c);
+            }
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn normalize_nfc(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        output.extend(value.nfc());
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn normalize_nfd(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        output.extend(value.nfd());
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn normalize_nfkc(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        output.extend(value.nfkc());
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn normalize_nfkd(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        output.extend(value.nfkd());
+    })?;
+    Ok(out.into_series())
+}
+
+// Combining diacritical mark blocks, which are used with Latin, Greek, and Cyrillic
+// letters. Marks of other scripts such as Devanagari vowel signs are kept.
+fn is_diacritic_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{1AB0}'..='\u{1AFF}'
+            | '\u{1DC0}'..='\u{1DFF}'
+            | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE20}'..='\u{FE2F}'
+    )
+}
+
+fn push_without_diacritics(value: &str, output: &mut String) {
+    // Recompose afterwards so characters that decompose without diacritics,
+    // like Hangul syllables, come back unchanged
+    output.extend(value.nfd().filter(|&c| !is_diacritic_mark(c)).nfc());
+}
+
+#[polars_expr(output_type=String)]
+fn remove_diacritics(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        push_without_diacritics(value, output);
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct SlugifyKwargs {
+    separator: String,
+    lowercase: bool,
+    max_length: Option<usize>,
+    null_mode: NullMode,
+}
+
+impl Default for SlugifyKwargs {
+    fn default() -> Self {
+        SlugifyKwargs {
+            separator: "-".to_string(),
+            lowercase: true,
+            max_length: None,
+            null_mode: NullMode::Propagate,
+        }
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn slugify(inputs: &[Series], kwargs: SlugifyKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let separator_length = kwargs.separator.chars().count();
+    // Each slice reuses its own scratch buffer across rows
+    par_map_slices(ca, |ca| {
+        let mut buffer = String::new();
+        let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+            buffer.clear();
+            push_without_diacritics(value, &mut buffer);
+
+            let mut remaining = kwargs.max_length.unwrap_or(usize::MAX);
+
+            // Any run of non-alphanumeric characters becomes a single separator
+            for (i, word) in buffer.split(|c: char| !c.is_alphanumeric()).filter(|word| !word.is_empty()).enumerate() {
+                // Stop rather than leaving a trailing or partial separator
+                if i > 0 {
+                    if remaining <= separator_length {
+                        break;
+                    }
+                    output.push_str(&kwargs.separator);
+                    remaining -= separator_length;
+                }
+
+                for c in word.chars().take(remaining) {
+                    if kwargs.lowercase {
+                        output.extend(c.to_lowercase());
+                    } else {
+                        output.push(c);
+                    }
+                    remaining -= 1;
+                }
+
+                if remaining == 0 {
+                    break;
+                }
+            }
+        });
+        Ok(out.into_series())
+    })
+}
+
+#[derive(Deserialize)]
+pub struct Base64Kwargs {
+    #[serde(default)]
+    url_safe: bool,
+    #[serde(default = "default_true")]
+    strict: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn base64_engine(url_safe: bool) -> GeneralPurpose {
+    let alphabet = if url_safe { &alphabet::URL_SAFE } else { &alphabet::STANDARD };
+    // Accept input with or without padding when decoding
+    let config = GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+    GeneralPurpose::new(alphabet, config)
+}
+
+#[polars_expr(output_type=String)]
+fn base64_encode(inputs: &[Series], kwargs: Base64Kwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let engine = base64_engine(kwargs.url_safe);
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        engine.encode_string(value, output);
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn base64_decode(inputs: &[Series], kwargs: Base64Kwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let engine = base64_engine(kwargs.url_safe);
+    par_map_slices(ca, |ca| {
+        let out: StringChunked = ca
+            .iter()
+            .map(|value| {
+                let Some(value) = value else {
+                    return Ok(None);
+                };
+                let decoded = engine
+                    .decode(value)
+                    .map_err(|e| polars_err!(ComputeError: "invalid base64 value '{}': {}", value, e))
+                    .and_then(|bytes| {
+                        String::from_utf8(bytes)
+                            .map_err(|_| polars_err!(ComputeError: "base64 value '{}' is not valid UTF-8", value))
+                    });
+                match decoded {
+                    Ok(decoded) => Ok(Some(decoded)),
+                    // Make invalid values null unless in strict mode
+                    Err(_) if !kwargs.strict => Ok(None),
+                    Err(e) => Err(e),
+                }
+            })
+            .collect::<PolarsResult<StringChunked>>()?;
+        Ok(out.with_name(ca.name().clone()).into_series())
+    })
+}
+
+#[derive(Deserialize)]
+pub struct HexKwargs {
+    #[serde(default)]
+    uppercase: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn hex_encode(inputs: &[Series], kwargs: HexKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        if kwargs.uppercase {
+            output.push_str(&hex::encode_upper(value));
+        } else {
+            output.push_str(&hex::encode(value));
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn hex_decode(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_try_apply_into_string(ca, |value: &str, output: &mut String| -> PolarsResult<()> {
+        let bytes = hex::decode(value).map_err(|e| polars_err!(ComputeError: "invalid hex value '{}': {}", value, e))?;
+        let decoded =
+            std::str::from_utf8(&bytes).map_err(|_| polars_err!(ComputeError: "hex value '{}' is not valid UTF-8", value))?;
+        output.push_str(decoded);
+        Ok(())
+    })?;
+    Ok(out.into_series())
+}
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+#[derive(Deserialize)]
+pub struct Base32Kwargs {
+    #[serde(default = "default_true")]
+    padding: bool,
+    #[serde(default)]
+    lowercase: bool,
+    #[serde(default = "default_true")]
+    strict: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn push_base32(bytes: &[u8], padding: bool, lowercase: bool, output: &mut String) {
+    let start = output.len();
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+    let mut push_symbol = |index: u16| {
+        let symbol = BASE32_ALPHABET[(index & 31) as usize] as char;
+        output.push(if lowercase { symbol.to_ascii_lowercase() } else { symbol });
+    };
+    for &byte in bytes {
+        buffer = (buffer << 8) | byte as u16;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            push_symbol(buffer >> bits);
+        }
+        buffer &= (1 << bits) - 1;
+    }
+    if bits > 0 {
+        push_symbol(buffer << (5 - bits));
+    }
+    // Pad the output to a multiple of eight symbols
+    while padding && !(output.len() - start).is_multiple_of(8) {
+        output.push('=');
+    }
+}
+
+// Accept input in either case, with or without padding
+fn decode_base32(value: &str) -> PolarsResult<Vec<u8>> {
+    let symbols = value.trim_end_matches('=');
+    polars_ensure!(
+        !matches!(symbols.len() % 8, 1 | 3 | 6),
+        ComputeError: "invalid base32 value '{}': invalid length", value
+    );
+    let mut bytes = Vec::with_capacity(symbols.len() * 5 / 8);
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+    for c in symbols.chars() {
+        let index = match c.to_ascii_uppercase() {
+            c @ 'A'..='Z' => c as u16 - 'A' as u16,
+            c @ '2'..='7' => c as u16 - '2' as u16 + 26,
+            _ => polars_bail!(ComputeError: "invalid base32 value '{}': invalid character '{}'", value, c),
+        };
+        buffer = (buffer << 5) | index;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Ok(bytes)
+}
+
+#[polars_expr(output_type=String)]
+fn base32_encode(inputs: &[Series], kwargs: Base32Kwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        push_base32(value.as_bytes(), kwargs.padding, kwargs.lowercase, output);
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn base32_decode(inputs: &[Series], kwargs: Base32Kwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    par_map_slices(ca, |ca| {
+        let out: StringChunked = ca
+            .iter()
+            .map(|value| {
+                let Some(value) = value else {
+                    return Ok(None);
+                };
+                let decoded = decode_base32(value).and_then(|bytes| {
+                    String::from_utf8(bytes)
+                        .map_err(|_| polars_err!(ComputeError: "base32 value '{}' is not valid UTF-8", value))
+                });
+                match decoded {
+                    Ok(decoded) => Ok(Some(decoded)),
+                    // Make invalid values null unless in strict mode
+                    Err(_) if !kwargs.strict => Ok(None),
+                    Err(e) => Err(e),
+                }
+            })
+            .collect::<PolarsResult<StringChunked>>()?;
+        Ok(out.with_name(ca.name().clone()).into_series())
+    })
+}
+
+// Everything except the unreserved characters of RFC 3986
+const URL_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+
+#[derive(Deserialize)]
+pub struct UrlEncodeKwargs {
+    #[serde(default)]
+    encode_spaces_as_plus: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn url_encode(inputs: &[Series], kwargs: UrlEncodeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        if kwargs.encode_spaces_as_plus {
+            for (i, part) in value.split(' ').enumerate() {
+                if i > 0 {
+                    output.push('+');
+                }
+                output.extend(utf8_percent_encode(part, URL_ENCODE_SET));
+            }
+        } else {
+            output.extend(utf8_percent_encode(value, URL_ENCODE_SET));
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn url_decode(inputs: &[Series], kwargs: UrlEncodeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    par_map_slices(ca, |ca| {
+        let mut bytes: Vec<u8> = Vec::new();
+        let out: StringChunked = ca.try_apply_into_string_amortized(|value: &str, output: &mut String| -> PolarsResult<()> {
+            bytes.clear();
+            let mut iter = value.bytes();
+            while let Some(byte) = iter.next() {
+                match byte {
+                    b'%' => {
+                        let high = iter.next().and_then(|b| (b as char).to_digit(16));
+                        let low = iter.next().and_then(|b| (b as char).to_digit(16));
+                        let (Some(high), Some(low)) = (high, low) else {
+                            polars_bail!(ComputeError: "invalid percent-encoded sequence in '{}'", value);
+                        };
+                        bytes.push((high * 16 + low) as u8);
+                    },
+                    b'+' if kwargs.encode_spaces_as_plus => bytes.push(b' '),
+                    _ => bytes.push(byte),
+                }
+            }
+            let decoded = std::str::from_utf8(&bytes)
+                .map_err(|_| polars_err!(ComputeError: "percent-decoded value '{}' is not valid UTF-8", value))?;
+            output.push_str(decoded);
+            Ok(())
+        })?;
+        Ok(out.into_series())
+    })
+}
+
+#[polars_expr(output_type=String)]
+fn html_escape(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        html_escape::encode_quoted_attribute_to_string(value, output);
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn html_unescape(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    // Unknown entities are left as they are
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        html_escape::decode_html_entities_to_string(value, output);
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct ReplaceRegexKwargs {
+    pattern: String,
+    replacement: String,
+    #[serde(default)]
+    case_insensitive: bool,
+    #[serde(default = "default_true")]
+    replace_all: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn replace_regex(inputs: &[Series], kwargs: ReplaceRegexKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let regex = compile_regex(&kwargs.pattern, kwargs.case_insensitive)?;
+    // The replacement string expands $1 and ${name} backreferences
+    let replacement = kwargs.replacement.as_str();
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        if kwargs.replace_all {
+            output.push_str(&regex.replace_all(value, replacement));
+        } else {
+            output.push_str(&regex.replace(value, replacement));
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct ExtractRegexKwargs {
+    pattern: String,
+    group: usize,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn compile_regex_with_group(kwargs: &ExtractRegexKwargs) -> PolarsResult<Regex> {
+    let regex = compile_regex(&kwargs.pattern, false)?;
+    // Group 0 is the whole match, so a pattern with n groups accepts 0..=n
+    polars_ensure!(
+        kwargs.group < regex.captures_len(),
+        ComputeError: "regex pattern '{}' has no capture group {}", kwargs.pattern, kwargs.group
+    );
+    Ok(regex)
+}
+
+#[polars_expr(output_type=String)]
+fn extract_regex(inputs: &[Series], kwargs: ExtractRegexKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let regex = compile_regex_with_group(&kwargs)?;
+    par_map_slices(ca, |ca| {
+        let out: StringChunked = ca
+            .iter()
+            .map(|value| {
+                value
+                    .and_then(|value| regex.captures(value))
+                    .and_then(|captures| captures.get(kwargs.group))
+                    .map(|m| m.as_str())
+            })
+            .collect();
+        Ok(out.with_name(ca.name().clone()).into_series())
+    })
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn extract_all_regex(inputs: &[Series], kwargs: ExtractRegexKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let regex = compile_regex_with_group(&kwargs)?;
+    par_map_slices(ca, |ca| {
+        let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.get_values_size());
+        for value in ca.iter() {
+            match value {
+                // Matches where the chosen group did not participate are skipped
+                Some(s) => builder.append_values_iter(
+                    regex
+                        .captures_iter(s)
+                        .filter_map(|captures| captures.get(kwargs.group))
+                        .map(|m| m.as_str()),
+                ),
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into_series())
+    })
+}
+
+#[derive(Deserialize)]
+pub struct PadKwargs {
+    length: usize,
+    fill: String,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn push_fill(fill: &str, count: usize, out: &mut String) {
+    // Repeat the fill and cut it off mid-way when it does not divide evenly
+    out.extend(fill.chars().cycle().take(count));
+}
+
+#[polars_expr(output_type=String)]
+fn pad_start(inputs: &[Series], kwargs: PadKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(!kwargs.fill.is_empty(), ComputeError: "fill must not be empty");
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        push_fill(&kwargs.fill, kwargs.length.saturating_sub(value.chars().count()), output);
+        output.push_str(value);
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn pad_end(inputs: &[Series], kwargs: PadKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(!kwargs.fill.is_empty(), ComputeError: "fill must not be empty");
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        output.push_str(value);
+        push_fill(&kwargs.fill, kwargs.length.saturating_sub(value.chars().count()), output);
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct CenterKwargs {
+    length: usize,
+    fill: String,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn center(inputs: &[Series], kwargs: CenterKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(!kwargs.fill.is_empty(), ComputeError: "fill must not be empty");
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        // Width is counted in chars so that accented text is not over-padded
+        let padding = kwargs.length.saturating_sub(value.chars().count());
+        let left = padding / 2;
+        push_fill(&kwargs.fill, left, output);
+        output.push_str(value);
+        push_fill(&kwargs.fill, padding - left, output);
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct TrimKwargs {
+    #[serde(default)]
+    characters: String,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+// Any char in the set is trimmed, falling back to whitespace for an empty set
+fn is_trim_char(c: char, characters: &str) -> bool {
+    if characters.is_empty() {
+        c.is_whitespace()
+    } else {
+        characters.contains(c)
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn trim_chars(inputs: &[Series], kwargs: TrimKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        output.push_str(value.trim_matches(|c| is_trim_char(c, &kwargs.characters)));
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn ltrim_chars(inputs: &[Series], kwargs: TrimKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        output.push_str(value.trim_start_matches(|c| is_trim_char(c, &kwargs.characters)));
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn rtrim_chars(inputs: &[Series], kwargs: TrimKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        output.push_str(value.trim_end_matches(|c| is_trim_char(c, &kwargs.characters)));
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct SqueezeKwargs {
+    #[serde(default)]
+    keep_char: String,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn push_squeezed(value: &str, is_squeezed: impl Fn(char) -> bool, replacement: char, out: &mut String) {
+    let mut in_run = false;
+    for c in value.trim_matches(&is_squeezed).chars() {
+        if is_squeezed(c) {
+            if !in_run {
+                out.push(replacement);
+            }
+            in_run = true;
+        } else {
+            out.push(c);
+            in_run = false;
+        }
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn squeeze_whitespace(inputs: &[Series], kwargs: SqueezeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let mut chars = kwargs.keep_char.chars();
+    let keep_char = chars.next();
+    polars_ensure!(
+        chars.next().is_none(),
+        ComputeError: "keep_char must be a single character, got '{}'", kwargs.keep_char
+    );
+    // Without a chosen char, every whitespace run becomes a single space
+    let is_squeezed = |c: char| match keep_char {
+        Some(keep_char) => c == keep_char,
+        None => c.is_whitespace(),
+    };
+    let replacement = keep_char.unwrap_or(' ');
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        push_squeezed(value, is_squeezed, replacement, output);
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn reverse_string(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    // Reverse whole grapheme clusters to keep combining marks and emoji together
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        output.extend(value.graphemes(true).rev());
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct WordCountKwargs {
+    #[serde(default)]
+    pattern: Option<String>,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=UInt32)]
+fn word_count(inputs: &[Series], kwargs: WordCountKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    // A custom pattern replaces whitespace as the separator between words
+    let regex = kwargs.pattern.as_deref().map(|pattern| compile_regex(pattern, false)).transpose()?;
+    let out: UInt32Chunked = par_apply_nonnull_values_generic(ca, DataType::UInt32, |value: &str| {
+        let count = match &regex {
+            Some(regex) => regex.split(value).filter(|word| !word.is_empty()).count(),
+            None => value.split_whitespace().count(),
+        };
+        count as u32
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=UInt32)]
+fn grapheme_len(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: UInt32Chunked = par_apply_nonnull_values_generic(ca, DataType::UInt32, |value: &str| {
+        value.graphemes(true).count() as u32
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct TruncateKwargs {
+    length: usize,
+    ellipsis: String,
+    #[serde(default)]
+    word_boundary: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn truncate_ellipsis(inputs: &[Series], kwargs: TruncateKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let ellipsis_len = kwargs.ellipsis.graphemes(true).count();
+    polars_ensure!(
+        ellipsis_len <= kwargs.length,
+        ComputeError: "ellipsis '{}' is longer than length {}", kwargs.ellipsis, kwargs.length
+    );
+    let keep = kwargs.length - ellipsis_len;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        // Lengths are counted in grapheme clusters, as a reader would
+        if value.graphemes(true).count() <= kwargs.length {
+            output.push_str(value);
+            return;
+        }
+        let cut = value.grapheme_indices(true).nth(keep).map_or(value.len(), |(i, _)| i);
+        let mut head = &value[..cut];
+        // Back up to the last whitespace unless the cut already falls between words
+        if kwargs.word_boundary && !value[cut..].starts_with(char::is_whitespace) {
+            if let Some(i) = head.rfind(char::is_whitespace) {
+                head = &head[..i];
+            }
+        }
+        output.push_str(head.trim_end());
+        output.push_str(&kwargs.ellipsis);
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct WrapKwargs {
+    width: usize,
+    #[serde(default = "default_true")]
+    break_long_words: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn push_wrapped_paragraph(paragraph: &str, kwargs: &WrapKwargs, out: &mut String) {
+    let mut line_len = 0;
+    for word in paragraph.split_whitespace() {
+        let mut word = word;
+        let mut word_len = word.graphemes(true).count();
+        if line_len > 0 {
+            if line_len + 1 + word_len <= kwargs.width {
+                out.push(' ');
+                line_len += 1;
+            } else {
+                out.push('\n');
+                line_len = 0;
+            }
+        }
+        // Hard-break a word that cannot fit on a line of its own
+        while kwargs.break_long_words && word_len > kwargs.width {
+            let cut = word.grapheme_indices(true).nth(kwargs.width).map_or(word.len(), |(i, _)| i);
+            out.push_str(&word[..cut]);
+            out.push('\n');
+            word = &word[cut..];
+            word_len -= kwargs.width;
+        }
+        out.push_str(word);
+        line_len += word_len;
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn wrap_text(inputs: &[Series], kwargs: WrapKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(kwargs.width > 0, ComputeError: "width must be greater than zero");
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        // Existing newlines are kept as paragraph breaks
+        for (i, paragraph) in value.split('\n').enumerate() {
+            if i > 0 {
+                output.push('\n');
+            }
+            push_wrapped_paragraph(paragraph, &kwargs, output);
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+fn string_pair(inputs: &[Series], null_mode: NullMode) -> PolarsResult<(StringChunked, StringChunked)> {
+    let left = apply_null_mode(inputs[0].str()?, null_mode)?;
+    let right = apply_null_mode(inputs[1].str()?, null_mode)?;
+    polars_ensure!(
+        left.len() == right.len(),
+        ComputeError: "both inputs must have the same length, got {} and {}", left.len(), right.len()
+    );
+    Ok((left, right))
+}
+
+fn levenshtein_distance(a: &[char], b: &[char]) -> usize {
+    // Keep the rows as short as the shorter string
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut previous: Vec<usize> = (0..=short.len()).collect();
+    let mut current: Vec<usize> = vec![0; short.len() + 1];
+    for (i, &lc) in long.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, &sc) in short.iter().enumerate() {
+            let substitution = previous[j] + usize::from(lc != sc);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[short.len()]
+}
+
+#[polars_expr(output_type=UInt32)]
+fn levenshtein(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let (left, right) = string_pair(inputs, kwargs.null_mode)?;
+    par_map_slice_pairs(&left, &right, |left, right| {
+        let out: UInt32Chunked = left
+            .iter()
+            .zip(right.iter())
+            .map(|(a, b)| {
+                let a: Vec<char> = a?.chars().collect();
+                let b: Vec<char> = b?.chars().collect();
+                Some(levenshtein_distance(&a, &b) as u32)
+            })
+            .collect();
+        Ok(out.with_name(left.name().clone()).into_series())
+    })
+}
+
+fn jaro_similarity(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    // Chars only match when they are no further apart than this window
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+    let mut matches = 0;
+    for (i, &ac) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == ac {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+    let a_order = a.iter().zip(&a_matched).filter(|(_, &m)| m).map(|(c, _)| c);
+    let b_order = b.iter().zip(&b_matched).filter(|(_, &m)| m).map(|(c, _)| c);
+    let transpositions = a_order.zip(b_order).filter(|(ac, bc)| ac != bc).count() / 2;
+    let matches = matches as f64;
+    (matches / a.len() as f64 + matches / b.len() as f64 + (matches - transpositions as f64) / matches) / 3.0
+}
+
+fn jaro_winkler_similarity(a: &[char], b: &[char], prefix_weight: f64) -> f64 {
+    let jaro = jaro_similarity(a, b);
+    let prefix = a.iter().zip(b).take(4).take_while(|(ac, bc)| ac == bc).count();
+    jaro + prefix as f64 * prefix_weight * (1.0 - jaro)
+}
+
+fn default_jaro_winkler_prefix_weight() -> f64 {
+    0.1
+}
+
+#[derive(Deserialize)]
+pub struct JaroWinklerKwargs {
+    #[serde(default = "default_jaro_winkler_prefix_weight")]
+    prefix_weight: f64,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=Float64)]
+fn jaro_winkler(inputs: &[Series], kwargs: JaroWinklerKwargs) -> PolarsResult<Series> {
+    let (left, right) = string_pair(inputs, kwargs.null_mode)?;
+    // Above 0.25 the prefix boost could push the similarity past 1
+    polars_ensure!(
+        (0.0..=0.25).contains(&kwargs.prefix_weight),
+        ComputeError: "prefix_weight must be between 0 and 0.25, got {}", kwargs.prefix_weight
+    );
+    par_map_slice_pairs(&left, &right, |left, right| {
+        let out: Float64Chunked = left
+            .iter()
+            .zip(right.iter())
+            .map(|(a, b)| {
+                let a: Vec<char> = a?.chars().collect();
+                let b: Vec<char> = b?.chars().collect();
+                Some(jaro_winkler_similarity(&a, &b, kwargs.prefix_weight))
+            })
+            .collect();
+        Ok(out.with_name(left.name().clone()).into_series())
+    })
+}
+
+fn default_on_length_mismatch() -> String {
+    "error".to_string()
+}
+
+fn default_hamming_fill() -> String {
+    " ".to_string()
+}
+
+#[derive(Deserialize)]
+pub struct HammingKwargs {
+    #[serde(default = "default_on_length_mismatch")]
+    on_length_mismatch: String,
+    #[serde(default = "default_hamming_fill")]
+    fill: String,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=UInt32)]
+fn hamming_distance(inputs: &[Series], kwargs: HammingKwargs) -> PolarsResult<Series> {
+    let (left, right) = string_pair(inputs, kwargs.null_mode)?;
+    polars_ensure!(
+        matches!(kwargs.on_length_mismatch.as_str(), "error" | "null" | "pad"),
+        ComputeError: "on_length_mismatch must be 'error', 'null' or 'pad', got '{}'", kwargs.on_length_mismatch
+    );
+    let mut fill = kwargs.fill.chars();
+    let (Some(fill), None) = (fill.next(), fill.next()) else {
+        polars_bail!(ComputeError: "fill must be a single character, got '{}'", kwargs.fill);
+    };
+    par_map_slice_pairs(&left, &right, |left, right| {
+        let out: UInt32Chunked = left
+            .iter()
+            .zip(right.iter())
+            .map(|(a, b)| -> PolarsResult<Option<u32>> {
+                let (Some(a), Some(b)) = (a, b) else {
+                    return Ok(None);
+                };
+                let a_len = a.chars().count();
+                let b_len = b.chars().count();
+                if a_len != b_len {
+                    match kwargs.on_length_mismatch.as_str() {
+                        "error" => polars_bail!(ComputeError: "'{}' and '{}' differ in length", a, b),
+                        "null" => return Ok(None),
+                        _ => {},
+                    }
+                }
+                // The shorter side is padded with the fill char up to the longer length
+                let length = a_len.max(b_len);
+                let a = a.chars().chain(std::iter::repeat(fill)).take(length);
+                let b = b.chars().chain(std::iter::repeat(fill)).take(length);
+                Ok(Some(a.zip(b).filter(|(ac, bc)| ac != bc).count() as u32))
+            })
+            .collect::<PolarsResult<UInt32Chunked>>()?;
+        Ok(out.with_name(left.name().clone()).into_series())
+    })
+}
+
+fn soundex_digit(c: char) -> Option<char> {
+    match c {
+        'B' | 'F' | 'P' | 'V' => Some('1'),
+        'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+        'D' | 'T' => Some('3'),
+        'L' => Some('4'),
+        'M' | 'N' => Some('5'),
+        'R' => Some('6'),
+        _ => None,
+    }
+}
+
+fn soundex_code(value: &str) -> Option<String> {
+    let mut folded = String::new();
+    push_without_diacritics(value, &mut folded);
+    // Anything that is not an ASCII letter, including a leading one, is ignored
+    let mut letters = folded.chars().filter(char::is_ascii_alphabetic).map(|c| c.to_ascii_uppercase());
+    let first = letters.next()?;
+    let mut code = String::with_capacity(4);
+    code.push(first);
+    let mut previous = soundex_digit(first);
+    for c in letters {
+        if code.len() == 4 {
+            break;
+        }
+        match soundex_digit(c) {
+            Some(digit) => {
+                if previous != Some(digit) {
+                    code.push(digit);
+                }
+                previous = Some(digit);
+            },
+            // H and W do not separate letters with the same code, vowels do
+            None if matches!(c, 'H' | 'W') => {},
+            None => previous = None,
+        }
+    }
+    while code.len() < 4 {
+        code.push('0');
+    }
+    Some(code)
+}
+
+#[polars_expr(output_type=String)]
+fn soundex(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    par_map_slices(ca, |ca| {
+        let out: StringChunked = ca.iter().map(|value| value.and_then(soundex_code)).collect();
+        Ok(out.with_name(ca.name().clone()).into_series())
+    })
+}
+
+fn default_metaphone_max_length() -> usize {
+    4
+}
+
+#[derive(Deserialize)]
+pub struct MetaphoneKwargs {
+    #[serde(default = "default_metaphone_max_length")]
+    max_length: usize,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn is_metaphone_vowel(c: Option<char>) -> bool {
+    matches!(c, Some('A' | 'E' | 'I' | 'O' | 'U'))
+}
+
+fn metaphone_code(value: &str, max_length: usize) -> Option<String> {
+    let mut folded = String::new();
+    push_without_diacritics(value, &mut folded);
+    let letters: Vec<char> = folded.chars().filter(char::is_ascii_alphabetic).map(|c| c.to_ascii_uppercase()).collect();
+    if letters.is_empty() {
+        return None;
+    }
+    let at = |i: usize| letters.get(i).copied();
+    let mut code = String::new();
+
+    // Initial letter exceptions, where only a skipped letter moves the start
+    let mut i = 0;
+    let mut start = 0;
+    match (at(0), at(1)) {
+        (Some('A'), Some('E')) | (Some('G' | 'K' | 'P'), Some('N')) | (Some('W'), Some('R')) => {
+            i = 1;
+            start = 1;
+        },
+        (Some('X'), _) => {
+            code.push('S');
+            i = 1;
+        },
+        (Some('W'), Some('H')) => {
+            code.push('W');
+            i = 2;
+        },
+        _ => {},
+    }
+
+    while i < letters.len() && code.len() < max_length {
+        let c = letters[i];
+        let prev = i.checked_sub(1).map(|p| letters[p]);
+        let next = at(i + 1);
+        let after_next = at(i + 2);
+        // Doubled letters sound once, except for C as in "accept"
+        if prev == Some(c) && c != 'C' {
+            i += 1;
+            continue;
+        }
+        match c {
+            'A' | 'E' | 'I' | 'O' | 'U' => {
+                if i == start {
+                    code.push(c);
+                }
+            },
+            'B' => {
+                if !(prev == Some('M') && next.is_none()) {
+                    code.push('B');
+                }
+            },
+            'C' => {
+                if prev == Some('S') && matches!(next, Some('E' | 'I' | 'Y')) {
+                    // Silent as in "science"
+                } else if next == Some('I') && after_next == Some('A') {
+                    code.push('X');
+                } else if matches!(next, Some('E' | 'I' | 'Y')) {
+                    code.push('S');
+                } else if next == Some('H') {
+                    code.push(if prev == Some('S') { 'K' } else { 'X' });
+                } else {
+                    code.push('K');
+                }
+            },
+            'D' => {
+                if next == Some('G') && matches!(after_next, Some('E' | 'I' | 'Y')) {
+                    code.push('J');
+                    i += 1;
+                } else {
+                    code.push('T');
+                }
+            },
+            'G' => {
+                let silent_gh = next == Some('H') && !is_metaphone_vowel(after_next);
+                let silent_gn = next == Some('N')
+                    && (after_next.is_none() || (after_next == Some('E') && at(i + 3) == Some('D') && at(i + 4).is_none()));
+                if silent_gh || silent_gn {
+                    // Silent as in "night" and "sign"
+                } else if matches!(next, Some('E' | 'I' | 'Y')) {
+                    code.push('J');
+                } else {
+                    code.push('K');
+                }
+            },
+            'H' => {
+                let after_digraph = matches!(prev, Some('C' | 'G' | 'P' | 'S' | 'T'));
+                let after_vowel = is_metaphone_vowel(prev) && !is_metaphone_vowel(next);
+                if !after_digraph && !after_vowel {
+                    code.push('H');
+                }
+            },
+            'K' => {
+                if prev != Some('C') {
+                    code.push('K');
+                }
+            },
+            'P' => code.push(if next == Some('H') { 'F' } else { 'P' }),
+            'Q' => code.push('K'),
+            'S' => {
+                if next == Some('H') || (next == Some('I') && matches!(after_next, Some('O' | 'A'))) {
+                    code.push('X');
+                } else {
+                    code.push('S');
+                }
+            },
+            'T' => {
+                if next == Some('I') && matches!(after_next, Some('O' | 'A')) {
+                    code.push('X');
+                } else if next == Some('H') {
+                    code.push('0');
+                } else if !(next == Some('C') && after_next == Some('H')) {
+                    code.push('T');
+                }
+            },
+            'V' => code.push('F'),
+            'W' | 'Y' => {
+                if is_metaphone_vowel(next) {
+                    code.push(c);
+                }
+            },
+            'X' => code.push_str("KS"),
+            'Z' => code.push('S'),
+            _ => code.push(c),
+        }
+        i += 1;
+    }
+    code.truncate(max_length);
+    Some(code)
+}
+
+#[polars_expr(output_type=String)]
+fn metaphone(inputs: &[Series], kwargs: MetaphoneKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(kwargs.max_length > 0, ComputeError: "max_length must be greater than zero");
+    par_map_slices(ca, |ca| {
+        let out: StringChunked = ca.iter().map(|value| value.and_then(|value| metaphone_code(value, kwargs.max_length))).collect();
+        Ok(out.with_name(ca.name().clone()).into_series())
+    })
+}
+
+fn default_ngram_separator() -> String {
+    " ".to_string()
+}
+
+#[derive(Deserialize)]
+pub struct NgramKwargs {
+    n: usize,
+    #[serde(default)]
+    char_level: bool,
+    #[serde(default = "default_ngram_separator")]
+    separator: String,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn ngrams(inputs: &[Series], kwargs: NgramKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(kwargs.n > 0, ComputeError: "n must be greater than zero");
+    par_map_slices(ca, |ca| {
+        let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.get_values_size());
+        for value in ca.iter() {
+            let Some(value) = value else {
+                builder.append_null();
+                continue;
+            };
+            // Char n-grams are plain substrings; the separator only joins words
+            if kwargs.char_level {
+                let bounds: Vec<usize> = value
+                    .grapheme_indices(true)
+                    .map(|(i, _)| i)
+                    .chain(std::iter::once(value.len()))
+                    .collect();
+                builder.append_values_iter(bounds.windows(kwargs.n + 1).map(|w| &value[w[0]..w[kwargs.n]]));
+            } else {
+                let words: Vec<&str> = value.split_whitespace().collect();
+                let grams: Vec<String> = words.windows(kwargs.n).map(|w| w.join(&kwargs.separator)).collect();
+                builder.append_values_iter(grams.iter().map(String::as_str));
+            }
+        }
+        Ok(builder.finish().into_series())
+    })
+}
+
+#[derive(Deserialize)]
+pub struct CountKwargs {
+    needle: String,
+    #[serde(default)]
+    overlapping: bool,
+    #[serde(default)]
+    case_insensitive: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn count_occurrences(haystack: &str, needle: &str, overlapping: bool) -> usize {
+    if !overlapping {
+        return haystack.matches(needle).count();
+    }
+    // Resume the search one char after the start of each match
+    let step = needle.chars().next().map_or(1, char::len_utf8);
+    let mut count = 0;
+    let mut start = 0;
+    while let Some(i) = haystack[start..].find(needle) {
+        count += 1;
+        start += i + step;
+    }
+    count
+}
+
+#[polars_expr(output_type=UInt32)]
+fn count_substring(inputs: &[Series], kwargs: CountKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(!kwargs.needle.is_empty(), ComputeError: "needle must not be empty");
+    let needle = if kwargs.case_insensitive { kwargs.needle.to_lowercase() } else { kwargs.needle.clone() };
+    let out: UInt32Chunked = par_apply_nonnull_values_generic(ca, DataType::UInt32, |value: &str| {
+        let count = if kwargs.case_insensitive {
+            count_occurrences(&value.to_lowercase(), &needle, kwargs.overlapping)
+        } else {
+            count_occurrences(value, &needle, kwargs.overlapping)
+        };
+        count as u32
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct FindKwargs {
+    needle: String,
+    #[serde(default)]
+    case_insensitive: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type_func=list_uint32_output)]
+fn find_all_positions(inputs: &[Series], kwargs: FindKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(!kwargs.needle.is_empty(), ComputeError: "needle must not be empty");
+    // Matching the escaped needle keeps case folding on the original text
+    let regex = compile_regex(&regex::escape(&kwargs.needle), kwargs.case_insensitive)?;
+    par_map_slices(ca, |ca| {
+        let mut builder =
+            ListPrimitiveChunkedBuilder::<UInt32Type>::new(ca.name().clone(), ca.len(), ca.len(), DataType::UInt32);
+        for value in ca.iter() {
+            let Some(value) = value else {
+                builder.append_null();
+                continue;
+            };
+            // Turn byte offsets into char offsets, counting only the gap since the last match
+            let mut last_byte = 0;
+            let mut last_char = 0;
+            builder.append_values_iter(regex.find_iter(value).map(|m| {
+                last_char += value[last_byte..m.start()].chars().count();
+                last_byte = m.start();
+                last_char as u32
+            }));
+        }
+        Ok(builder.finish().into_series())
+    })
+}
+
+fn default_mask_char() -> String {
+    "*".to_string()
+}
+
+#[derive(Deserialize)]
+pub struct MaskKwargs {
+    #[serde(default)]
+    keep_start: usize,
+    #[serde(default)]
+    keep_end: usize,
+    #[serde(default = "default_mask_char")]
+    mask_char: String,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn push_masked(value: &str, keep_start: usize, keep_end: usize, mask_char: &str, out: &mut String) {
+    let graphemes: Vec<&str> = value.graphemes(true).collect();
+    // Nothing is left to hide when the revealed parts already cover the string
+    if keep_start + keep_end >= graphemes.len() {
+        out.push_str(value);
+        return;
+    }
+    let masked_end = graphemes.len() - keep_end;
+    out.extend(graphemes[..keep_start].iter().copied());
+    for _ in keep_start..masked_end {
+        out.push_str(mask_char);
+    }
+    out.extend(graphemes[masked_end..].iter().copied());
+}
+
+#[polars_expr(output_type=String)]
+fn mask(inputs: &[Series], kwargs: MaskKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(!kwargs.mask_char.is_empty(), ComputeError: "mask_char must not be empty");
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        push_masked(value, kwargs.keep_start, kwargs.keep_end, &kwargs.mask_char, output);
+    })?;
+    Ok(out.into_series())
+}
+
+fn default_reveal_first() -> usize {
+    1
+}
+
+#[derive(Deserialize)]
+pub struct MaskEmailKwargs {
+    #[serde(default = "default_reveal_first")]
+    reveal_first: usize,
+    #[serde(default)]
+    mask_invalid: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn mask_email(inputs: &[Series], kwargs: MaskEmailKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        match value.split_once('@') {
+            Some((local, domain)) if !local.is_empty() && !domain.is_empty() && !domain.contains('@') => {
+                push_masked(local, kwargs.reveal_first, 0, "*", output);
+                output.push('@');
+                output.push_str(domain);
+            },
+            // Anything without exactly one '@' is not treated as an address
+            _ if kwargs.mask_invalid => push_masked(value, kwargs.reveal_first, 0, "*", output),
+            _ => output.push_str(value),
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+// A pragmatic address shape rather than the full RFC 5322 grammar
+const EMAIL_PATTERN: &str = r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}";
+
+#[derive(Deserialize)]
+pub struct ExtractEmailsKwargs {
+    #[serde(default)]
+    dedupe: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn extract_emails(inputs: &[Series], kwargs: ExtractEmailsKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let regex = compile_regex(EMAIL_PATTERN, false)?;
+    par_map_slices(ca, |ca| {
+        let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.get_values_size());
+        let mut emails: Vec<&str> = Vec::new();
+        for value in ca.iter() {
+            let Some(value) = value else {
+                builder.append_null();
+                continue;
+            };
+            emails.clear();
+            for m in regex.find_iter(value) {
+                // Keep the first occurrence so the order of appearance is preserved
+                if !kwargs.dedupe || !emails.contains(&m.as_str()) {
+                    emails.push(m.as_str());
+                }
+            }
+            builder.append_values_iter(emails.iter().copied());
+        }
+        Ok(builder.finish().into_series())
+    })
+}
+
+fn default_url_schemes() -> Vec<String> {
+    vec!["http".to_string(), "https".to_string()]
+}
+
+#[derive(Deserialize)]
+pub struct ExtractUrlKwargs {
+    #[serde(default = "default_url_schemes")]
+    schemes: Vec<String>,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn trim_url_punctuation(url: &str) -> &str {
+    let mut url = url;
+    // Drop sentence punctuation, but keep a closing bracket that the URL itself opened
+    while let Some(last) = url.chars().last() {
+        let unbalanced = match last {
+            ')' => url.matches('(').count() < url.matches(')').count(),
+            ']' => url.matches('[').count() < url.matches(']').count(),
+            _ => matches!(last, '.' | ',' | ';' | ':' | '!' | '?' | '\'' | '"'),
+        };
+        if !unbalanced {
+            break;
+        }
+        url = &url[..url.len() - last.len_utf8()];
+    }
+    url
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn extract_urls(inputs: &[Series], kwargs: ExtractUrlKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(!kwargs.schemes.is_empty(), ComputeError: "schemes must not be empty");
+    let schemes: Vec<String> = kwargs.schemes.iter().map(|scheme| regex::escape(scheme)).collect();
+    let pattern = format!(r#"\b(?:{})://[^\s<>"]+"#, schemes.join("|"));
+    let regex = compile_regex(&pattern, true)?;
+    par_map_slices(ca, |ca| {
+        let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.get_values_size());
+        for value in ca.iter() {
+            match value {
+                Some(s) => builder.append_values_iter(regex.find_iter(s).map(|m| trim_url_punctuation(m.as_str()))),
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into_series())
+    })
+}
+
+#[derive(Deserialize)]
+pub struct NumberExtractKwargs {
+    #[serde(default)]
+    allow_thousands_separator: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type_func=list_float64_output)]
+fn extract_numbers(inputs: &[Series], kwargs: NumberExtractKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let pattern = if kwargs.allow_thousands_separator {
+        r"[-+]?(?:\d{1,3}(?:,\d{3})+|\d+)(?:\.\d+)?|[-+]?\.\d+"
+    } else {
+        r"[-+]?\d+(?:\.\d+)?|[-+]?\.\d+"
+    };
+    let regex = compile_regex(pattern, false)?;
+    par_map_slices(ca, |ca| {
+        let mut builder =
+            ListPrimitiveChunkedBuilder::<Float64Type>::new(ca.name().clone(), ca.len(), ca.len(), DataType::Float64);
+        let mut digits = String::new();
+        for value in ca.iter() {
+            let Some(value) = value else {
+                builder.append_null();
+                continue;
+            };
+            builder.append_values_iter(regex.find_iter(value).filter_map(|m| {
+                digits.clear();
+                digits.extend(m.as_str().chars().filter(|&c| c != ','));
+                // The pattern only matches valid literals, so parsing cannot fail
+                digits.parse::<f64>().ok()
+            }));
+        }
+        Ok(builder.finish().into_series())
+    })
+}
+
+const NUMBER_WORDS_ONES: [&str; 20] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven", "twelve",
+    "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+];
+
+const NUMBER_WORDS_TENS: [&str; 10] = ["", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+
+const NUMBER_WORDS_SCALES: [&str; 5] = ["", "thousand", "million", "billion", "trillion"];
+
+// Everything below a quadrillion, the first scale without a word above
+const NUMBER_WORDS_LIMIT: u64 = 1_000_000_000_000_000;
+
+#[derive(Deserialize)]
+pub struct NumberToWordsKwargs {
+    #[serde(default)]
+    use_and: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn push_below_hundred(n: u64, out: &mut String) {
+    if n < 20 {
+        out.push_str(NUMBER_WORDS_ONES[n as usize]);
+    } else {
+        let (tens, ones) = (n / 10, n % 10);
+        out.push_str(NUMBER_WORDS_TENS[tens as usize]);
+        if ones > 0 {
+            out.push('-');
+            out.push_str(NUMBER_WORDS_ONES[ones as usize]);
+        }
+    }
+}
+
+fn number_to_words_value(n: i64, use_and: bool) -> PolarsResult<String> {
+    let magnitude = n.unsigned_abs();
+    polars_ensure!(
+        magnitude < NUMBER_WORDS_LIMIT,
+        ComputeError: "{} is too large to convert to words, the limit is below one quadrillion", n
+    );
+    let mut out = String::new();
+    if n < 0 {
+        out.push_str("minus ");
+    }
+    if magnitude == 0 {
+        out.push_str(NUMBER_WORDS_ONES[0]);
+        return Ok(out);
+    }
+    let mut groups = Vec::new();
+    let mut rest = magnitude;
+    while rest > 0 {
+        groups.push(rest % 1000);
+        rest /= 1000;
+    }
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        if !out.is_empty() && !out.ends_with(' ') {
+            out.push(' ');
+        }
+        // British style joins a trailing group below a hundred with "and"
+        if use_and && scale == 0 && group < 100 && magnitude >= 1000 {
+            out.push_str("and ");
+        }
+        let (hundreds, below_hundred) = (group / 100, group % 100);
+        if hundreds > 0 {
+            out.push_str(NUMBER_WORDS_ONES[hundreds as usize]);
+            out.push_str(" hundred");
+            if below_hundred > 0 {
+                out.push_str(if use_and { " and " } else { " " });
+            }
+        }
+        if below_hundred > 0 {
+            push_below_hundred(below_hundred, &mut out);
+        }
+        if scale > 0 {
+            out.push(' ');
+            out.push_str(NUMBER_WORDS_SCALES[scale]);
+        }
+    }
+    Ok(out)
+}
+
+#[polars_expr(output_type=String)]
+fn number_to_words(inputs: &[Series], kwargs: NumberToWordsKwargs) -> PolarsResult<Series> {
+    // Numeric strings are accepted too, but must all parse
+    let series = inputs[0].strict_cast(&DataType::Int64)?;
+    let ca: &Int64Chunked = series.i64()?;
+    ensure_no_nulls(ca.null_count(), kwargs.null_mode)?;
+    let out = par_map_slices(ca, |ca| {
+        let out: StringChunked = ca
+            .iter()
+            .map(|value| value.map(|value| number_to_words_value(value, kwargs.use_and)).transpose())
+            .collect::<PolarsResult<StringChunked>>()?;
+        Ok(out.into_series())
+    })?;
+    let out = fill_null_output(out.str()?.clone(), kwargs.null_mode);
+    Ok(out.with_name(ca.name().clone()).into_series())
+}
+
+fn ordinal_suffix(n: i64) -> &'static str {
+    let magnitude = n.unsigned_abs();
+    // Eleventh to thirteenth break the usual last-digit rule
+    if (11..=13).contains(&(magnitude % 100)) {
+        return "th";
+    }
+    match magnitude % 10 {
+        1 => "st",
+        2 => "nd",
+        3 => "rd",
+        _ => "th",
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn ordinalize(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let series = inputs[0].strict_cast(&DataType::Int64)?;
+    let ca: &Int64Chunked = series.i64()?;
+    ensure_no_nulls(ca.null_count(), kwargs.null_mode)?;
+    let out = par_map_slices(ca, |ca| {
+        let out: StringChunked = ca.iter().map(|value| value.map(|value| format!("{}{}", value, ordinal_suffix(value)))).collect();
+        Ok(out.into_series())
+    })?;
+    let out = fill_null_output(out.str()?.clone(), kwargs.null_mode);
+    Ok(out.with_name(ca.name().clone()).into_series())
+}
+
+const ROMAN_NUMERALS: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+fn push_roman(n: u32, out: &mut String) {
+    let mut rest = n;
+    for (value, numeral) in ROMAN_NUMERALS {
+        while rest >= value {
+            out.push_str(numeral);
+            rest -= value;
+        }
+    }
+}
+
+fn roman_value(numeral: &str) -> Option<u32> {
+    let upper = numeral.to_ascii_uppercase();
+    let mut total = 0;
+    let mut rest = upper.as_str();
+    for (value, symbol) in ROMAN_NUMERALS {
+        while let Some(tail) = rest.strip_prefix(symbol) {
+            total += value;
+            rest = tail;
+        }
+    }
+    if !rest.is_empty() || !(1..=3999).contains(&total) {
+        return None;
+    }
+    // Only the canonical spelling is accepted, which rules out "IIII" and "VX"
+    let mut canonical = String::new();
+    push_roman(total, &mut canonical);
+    (canonical == upper).then_some(total)
+}
+
+#[polars_expr(output_type=String)]
+fn roman_encode(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let series = inputs[0].strict_cast(&DataType::Int64)?;
+    let ca: &Int64Chunked = series.i64()?;
+    ensure_no_nulls(ca.null_count(), kwargs.null_mode)?;
+    let out = par_map_slices(ca, |ca| {
+        let out: StringChunked = ca
+            .iter()
+            .map(|value| -> PolarsResult<Option<String>> {
+                let Some(value) = value else {
+                    return Ok(None);
+                };
+                polars_ensure!(
+                    (1..=3999).contains(&value),
+                    ComputeError: "{} cannot be written as a Roman numeral, expected 1 to 3999", value
+                );
+                let mut numeral = String::new();
+                push_roman(value as u32, &mut numeral);
+                Ok(Some(numeral))
+            })
+            .collect::<PolarsResult<StringChunked>>()?;
+        Ok(out.into_series())
+    })?;
+    let out = fill_null_output(out.str()?.clone(), kwargs.null_mode);
+    Ok(out.with_name(ca.name().clone()).into_series())
+}
+
+#[derive(Deserialize)]
+pub struct RomanDecodeKwargs {
+    #[serde(default = "default_true")]
+    strict: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=Int64)]
+fn roman_decode(inputs: &[Series], kwargs: RomanDecodeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    par_map_slices(ca, |ca| {
+        let out: Int64Chunked = ca
+            .iter()
+            .map(|value| -> PolarsResult<Option<i64>> {
+                let Some(value) = value else {
+                    return Ok(None);
+                };
+                match roman_value(value) {
+                    Some(decoded) => Ok(Some(decoded as i64)),
+                    None if kwargs.strict => polars_bail!(ComputeError: "invalid Roman numeral '{}'", value),
+                    None => Ok(None),
+                }
+            })
+            .collect::<PolarsResult<Int64Chunked>>()?;
+        Ok(out.with_name(ca.name().clone()).into_series())
+    })
+}
+
+#[derive(Deserialize)]
+pub struct RepeatKwargs {
+    count: usize,
+    #[serde(default)]
+    separator: String,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn repeat(inputs: &[Series], kwargs: RepeatKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_try_apply_into_string(ca, |value: &str, output: &mut String| -> PolarsResult<()> {
+        // A single string value cannot hold more than u32::MAX bytes
+        let total_len = (value.len() + kwargs.separator.len())
+            .checked_mul(kwargs.count)
+            .map(|len| len.saturating_sub(kwargs.separator.len()));
+        polars_ensure!(
+            total_len.is_some_and(|len| u32::try_from(len).is_ok()),
+            ComputeError: "repeating '{}' {} times exceeds the maximum string length", value, kwargs.count
+        );
+        for i in 0..kwargs.count {
+            if i > 0 {
+                output.push_str(&kwargs.separator);
+            }
+            output.push_str(value);
+        }
+        Ok(())
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct StripHtmlKwargs {
+    #[serde(default = "default_true")]
+    drop_script_style: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn strip_html(inputs: &[Series], kwargs: StripHtmlKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let script_style = compile_regex(r"(?s)<script\b[^>]*>.*?</script\s*>|<style\b[^>]*>.*?</style\s*>", true)?;
+    // Block-level tags separate words, inline ones like <b> do not
+    let block_tags = compile_regex(
+        r"</?(?:br|p|div|li|ul|ol|tr|td|th|h[1-6]|table|section|article|header|footer|blockquote|pre|hr)\b[^>]*>",
+        true,
+    )?;
+    let tags = compile_regex(r"(?s)<!--.*?-->|<[^>]*>", false)?;
+    par_map_slices(ca, |ca| {
+        let mut text = String::new();
+        let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+            let value = if kwargs.drop_script_style { script_style.replace_all(value, " ") } else { value.into() };
+            let value = block_tags.replace_all(&value, " ");
+            let value = tags.replace_all(&value, "");
+            // Decode only after the tags are gone, so an escaped "&lt;b&gt;" survives as text
+            text.clear();
+            html_escape::decode_html_entities_to_string(&value, &mut text);
+            push_squeezed(&text, char::is_whitespace, ' ', output);
+        });
+        Ok(out.into_series())
+    })
+}
+
+fn is_pictographic(c: char) -> bool {
+    matches!(
+        c,
+        '\u{231A}'..='\u{231B}'
+            | '\u{2328}'
+            | '\u{23CF}'
+            | '\u{23E9}'..='\u{23F3}'
+            | '\u{23F8}'..='\u{23FA}'
+            | '\u{2600}'..='\u{27BF}'
+            | '\u{2B05}'..='\u{2B07}'
+            | '\u{2B1B}'..='\u{2B1C}'
+            | '\u{2B50}'
+            | '\u{2B55}'
+            | '\u{1F000}'..='\u{1FAFF}'
+    )
+}
+
+fn is_emoji_cluster(grapheme: &str) -> bool {
+    // A variation selector or keycap mark turns text symbols like "©" or "1" into emoji
+    grapheme.chars().any(|c| is_pictographic(c) || c == '\u{FE0F}' || c == '\u{20E3}')
+}
+
+#[polars_expr(output_type=String)]
+fn remove_emoji(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    // Whole grapheme clusters go, so ZWJ sequences and skin tones leave nothing behind
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        output.extend(value.graphemes(true).filter(|grapheme| !is_emoji_cluster(grapheme)));
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn extract_emoji(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    par_map_slices(ca, |ca| {
+        let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.get_values_size());
+        for value in ca.iter() {
+            match value {
+                Some(s) => builder.append_values_iter(s.graphemes(true).filter(|grapheme| is_emoji_cluster(grapheme))),
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into_series())
+    })
+}
+
+fn is_zero_width(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}' | '\u{180E}' | '\u{200B}'..='\u{200D}' | '\u{2060}'..='\u{2064}' | '\u{FEFF}'
+    )
+}
+
+fn is_directional_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+#[derive(Deserialize)]
+pub struct StripZeroWidthKwargs {
+    #[serde(default)]
+    strip_directional: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn strip_zero_width(inputs: &[Series], kwargs: StripZeroWidthKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        let mut chars = value.chars().peekable();
+        let mut prev: Option<char> = None;
+        while let Some(c) = chars.next() {
+            // A joiner between two pictographs is what holds an emoji sequence together
+            let in_emoji = c == '\u{200D}'
+                && prev.is_some_and(|p| is_pictographic(p) || p == '\u{FE0F}')
+                && chars.peek().is_some_and(|&n| is_pictographic(n));
+            let stripped_zero_width = is_zero_width(c) && !in_emoji;
+            let stripped_directional = kwargs.strip_directional && is_directional_mark(c);
+            if !stripped_zero_width && !stripped_directional {
+                output.push(c);
+            }
+            prev = Some(c);
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+fn default_rot_shift() -> i32 {
+    13
+}
+
+#[derive(Deserialize)]
+pub struct RotKwargs {
+    #[serde(default = "default_rot_shift")]
+    shift: i32,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn rot_n(inputs: &[Series], kwargs: RotKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    // Negative and oversized shifts wrap around the alphabet
+    let shift = kwargs.shift.rem_euclid(26) as u8;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        output.extend(value.chars().map(|c| match c {
+            'a'..='z' => ((c as u8 - b'a' + shift) % 26 + b'a') as char,
+            'A'..='Z' => ((c as u8 - b'A' + shift) % 26 + b'A') as char,
+            _ => c,
+        }));
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct PalindromeKwargs {
+    #[serde(default)]
+    ignore_case: bool,
+    #[serde(default)]
+    ignore_non_alnum: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=Boolean)]
+fn is_palindrome(inputs: &[Series], kwargs: PalindromeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: BooleanChunked = par_apply_nonnull_values_generic(ca, DataType::Boolean, |value: &str| {
+        // Compare grapheme clusters so accented letters read the same both ways
+        let graphemes: Vec<String> = value
+            .graphemes(true)
+            .filter(|grapheme| !kwargs.ignore_non_alnum || grapheme.chars().next().is_some_and(char::is_alphanumeric))
+            .map(|grapheme| if kwargs.ignore_case { grapheme.to_lowercase() } else { grapheme.to_string() })
+            .collect();
+        graphemes.iter().eq(graphemes.iter().rev())
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct InitialsKwargs {
+    #[serde(default = "default_true")]
+    uppercase: bool,
+    #[serde(default)]
+    separator: String,
+    #[serde(default)]
+    max_count: Option<usize>,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn initials(inputs: &[Series], kwargs: InitialsKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        // Words that do not start with a letter, like "3rd", have no initial
+        let letters = value
+            .split_whitespace()
+            .filter_map(|word| word.chars().next().filter(|c| c.is_alphabetic()))
+            .take(kwargs.max_count.unwrap_or(usize::MAX));
+        for (i, letter) in letters.enumerate() {
+            if i > 0 {
+                output.push_str(&kwargs.separator);
+            }
+            if kwargs.uppercase {
+                output.extend(letter.to_uppercase());
+            } else {
+                output.push(letter);
+            }
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+fn default_ascii_replacement() -> String {
+    "?".to_string()
+}
+
+#[derive(Deserialize)]
+pub struct AsciiKwargs {
+    #[serde(default = "default_ascii_replacement")]
+    replacement: String,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn to_ascii(inputs: &[Series], kwargs: AsciiKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    // Transliterates whole scripts, not just accents, e.g. "北京" to "Bei Jing"
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        output.push_str(&deunicode::deunicode_with_tofu_cow(value, &kwargs.replacement));
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn longest_common_prefix(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let mut values = ca.iter().flatten();
+    // Nulls are skipped and an empty or all-null column has an empty prefix
+    let mut prefix = values.next().unwrap_or("");
+    for value in values {
+        let end = prefix
+            .char_indices()
+            .zip(value.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or_else(|| prefix.len().min(value.len()), |((i, _), _)| i);
+        prefix = &prefix[..end];
+        if prefix.is_empty() {
+            break;
+        }
+    }
+    Ok(StringChunked::new(ca.name().clone(), [prefix]).into_series())
+}
+
+fn matches_affix(candidate: &str, affix: &str, case_insensitive: bool) -> bool {
+    if case_insensitive {
+        candidate.to_lowercase() == affix.to_lowercase()
+    } else {
+        candidate == affix
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SuffixKwargs {
+    suffix: String,
+    #[serde(default)]
+    case_insensitive: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn remove_suffix(inputs: &[Series], kwargs: SuffixKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let suffix_len = kwargs.suffix.chars().count();
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        // Split on a char boundary so case-folded matches of any width line up
+        let split = value.char_indices().rev().nth(suffix_len.saturating_sub(1)).map(|(i, _)| i);
+        match split {
+            Some(i) if suffix_len > 0 && matches_affix(&value[i..], &kwargs.suffix, kwargs.case_insensitive) => {
+                output.push_str(&value[..i])
+            },
+            _ => output.push_str(value),
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct PrefixKwargs {
+    prefix: String,
+    #[serde(default)]
+    case_insensitive: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn remove_prefix(inputs: &[Series], kwargs: PrefixKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let prefix_len = kwargs.prefix.chars().count();
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        let split = value.char_indices().nth(prefix_len).map_or(value.len(), |(i, _)| i);
+        let has_prefix = value[..split].chars().count() == prefix_len
+            && matches_affix(&value[..split], &kwargs.prefix, kwargs.case_insensitive);
+        output.push_str(if has_prefix { &value[split..] } else { value });
+    })?;
+    Ok(out.into_series())
+}
+
+fn is_capitalized_word(word: &str) -> bool {
+    let mut chars = word.chars();
+    let is_upper_start = chars.next().is_some_and(char::is_uppercase);
+    let rest = chars.as_str();
+    is_upper_start && (!rest.chars().any(char::is_uppercase) || !rest.chars().any(char::is_lowercase))
+}
+
+// Camel and pascal words are joined without separators; acronyms such as the "XML" in
+// "XMLHttpRequest" count as capitalized words.
+fn is_camel_words(value: &str, capitalize_first: bool) -> bool {
+    let words = split_into_words(value);
+    words.concat() == value
+        && words.iter().enumerate().all(|(i, word)| {
+            if i == 0 && !capitalize_first {
+                !word.chars().any(char::is_uppercase)
+            } else {
+                is_capitalized_word(word)
+            }
+        })
+}
+
+// A sentence is whitespace-separated words whose only uppercase letter is the first one,
+// e.g. "Hello world"; punctuation between the words is allowed.
+fn is_sentence_case(value: &str) -> bool {
+    let mut letters = value.chars().filter(|c| c.is_alphabetic());
+    value.chars().any(char::is_whitespace)
+        && letters.next().is_some_and(char::is_uppercase)
+        && letters.all(|c| !c.is_uppercase())
+}
+
+// Styles are tried in a fixed order and the first match wins, so a single lowercase word is
+// "snake", a single capitalized word is "pascal", and a single uppercase word is "constant".
+// Values without any letter are "unknown".
+fn detect_case_style(value: &str, rendered: &mut String) -> &'static str {
+    if !value.chars().any(char::is_alphabetic) {
+        return "unknown";
+    }
+    let mut renders_as = |delimiter: char, uppercase: bool| {
+        rendered.clear();
+        push_delimited_words(value, delimiter, uppercase, rendered);
+        rendered == value
+    };
+    if renders_as('_', true) {
+        "constant"
+    } else if renders_as('_', false) {
+        "snake"
+    } else if renders_as('-', false) {
+        "kebab"
+    } else if is_camel_words(value, false) {
+        "camel"
+    } else if is_camel_words(value, true) {
+        "pascal"
+    } else if is_sentence_case(value) {
+        "sentence"
+    } else {
+        "unknown"
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn detect_case(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    par_map_slices(ca, |ca| {
+        let mut rendered = String::new();
+        let out: StringChunked = ca.apply_into_string_amortized(|value: &str, output: &mut String| {
+            output.push_str(detect_case_style(value, &mut rendered));
+        });
+        Ok(out.into_series())
+    })
+}
+
+// Full case folding as a lowercase, uppercase, lowercase round trip, which also expands
+// "ß" to "ss" and maps the final sigma "ς" to "σ"
+fn push_case_folded(value: &str, out: &mut String) {
+    for c in value.chars().flat_map(char::to_lowercase) {
+        // The dotless "ı" folds to itself, the round trip would turn it into "i"
+        if c == 'ı' {
+            out.push(c);
+            continue;
+        }
+        out.extend(c.to_uppercase().flat_map(char::to_lowercase));
+    }
+}
+
+#[polars_expr(output_type=Boolean)]
+fn caseless_equals(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let (left, right) = string_pair(inputs, kwargs.null_mode)?;
+    par_map_slice_pairs(&left, &right, |left, right| {
+        let mut left_folded = String::new();
+        let mut right_folded = String::new();
+        let out: BooleanChunked = left
+            .iter()
+            .zip(right.iter())
+            .map(|(a, b)| {
+                let (a, b) = (a?, b?);
+                left_folded.clear();
+                right_folded.clear();
+                push_case_folded(a, &mut left_folded);
+                push_case_folded(b, &mut right_folded);
+                Some(left_folded == right_folded)
+            })
+            .collect();
+        Ok(out.with_name(left.name().clone()).into_series())
+    })
+}
+
+// Longest common block of a[alo..ahi] and b[blo..bhi] as (start in a, start in b, length).
+// Ties go to the block starting earliest in a, then earliest in b, like difflib.
+fn longest_match(a: &[char], b: &[char], (alo, ahi): (usize, usize), (blo, bhi): (usize, usize)) -> (usize, usize, usize) {
+    let mut best = (alo, blo, 0);
+    // lengths[j + 1] is the length of the match ending at the previous char of a and b[j]
+    let mut previous = vec![0; b.len() + 1];
+    let mut current = vec![0; b.len() + 1];
+    for (i, ac) in (alo..ahi).zip(&a[alo..ahi]) {
+        for (j, bc) in (blo..bhi).zip(&b[blo..bhi]) {
+            current[j + 1] = if ac == bc { previous[j] + 1 } else { 0 };
+            if current[j + 1] > best.2 {
+                best = (i + 1 - current[j + 1], j + 1 - current[j + 1], current[j + 1]);
+            }
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    best
+}
+
+// Chars in the matching blocks found by recursing on both sides of the longest match.
+// Unlike difflib, no chars are treated as junk, even in strings of 200 chars or more.
+fn matching_chars(a: &[char], b: &[char]) -> usize {
+    let mut matched = 0;
+    let mut queue = vec![((0, a.len()), (0, b.len()))];
+    while let Some(((alo, ahi), (blo, bhi))) = queue.pop() {
+        let (i, j, k) = longest_match(a, b, (alo, ahi), (blo, bhi));
+        if k == 0 {
+            continue;
+        }
+        matched += k;
+        queue.push(((alo, i), (blo, j)));
+        queue.push(((i + k, ahi), (j + k, bhi)));
+    }
+    matched
+}
+
+fn matching_ratio(a: &[char], b: &[char]) -> f64 {
+    let total = a.len() + b.len();
+    // Two empty strings are identical
+    if total == 0 {
+        return 1.0;
+    }
+    2.0 * matching_chars(a, b) as f64 / total as f64
+}
+
+#[polars_expr(output_type=Float64)]
+fn similarity_ratio(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let (left, right) = string_pair(inputs, kwargs.null_mode)?;
+    par_map_slice_pairs(&left, &right, |left, right| {
+        let out: Float64Chunked = left
+            .iter()
+            .zip(right.iter())
+            .map(|(a, b)| {
+                let a: Vec<char> = a?.chars().collect();
+                let b: Vec<char> = b?.chars().collect();
+                Some(matching_ratio(&a, &b))
+            })
+            .collect();
+        Ok(out.with_name(left.name().clone()).into_series())
+    })
+}
+
+const TOKENIZE_STOP_WORDS: &[&str] = &[
+    "a", "about", "after", "all", "also", "am", "an", "and", "any", "are", "as", "at", "be", "been", "before",
+    "being", "between", "both", "but", "by", "can", "could", "did", "do", "does", "doing", "down", "during", "each",
+    "few", "for", "from", "further", "had", "has", "have", "having", "he", "her", "here", "hers", "him", "his", "how",
+    "i", "if", "in", "into", "is", "it", "its", "just", "me", "more", "most", "my", "no", "nor", "not", "now", "of",
+    "off", "on", "once", "only", "or", "other", "our", "ours", "out", "over", "own", "same", "she", "should", "so",
+    "some", "such", "than", "that", "the", "their", "theirs", "them", "then", "there", "these", "they", "this",
+    "those", "through", "to", "too", "under", "until", "up", "very", "was", "we", "were", "what", "when", "where",
+    "which", "while", "who", "whom", "why", "will", "with", "would", "you", "your", "yours",
+];
+
+fn default_tokenize_pattern() -> String {
+    r"\W+".to_string()
+}
+
+#[derive(Deserialize)]
+pub struct TokenizeKwargs {
+    #[serde(default = "default_tokenize_pattern")]
+    pattern: String,
+    #[serde(default = "default_true")]
+    lowercase: bool,
+    #[serde(default)]
+    min_token_len: usize,
+    #[serde(default)]
+    drop_stopwords: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn tokenize(inputs: &[Series], kwargs: TokenizeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let regex = compile_regex(&kwargs.pattern, false)?;
+    par_map_slices(ca, |ca| {
+        let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.get_values_size());
+        let mut tokens: Vec<String> = Vec::new();
+        for value in ca.iter() {
+            let Some(value) = value else {
+                builder.append_null();
+                continue;
+            };
+            tokens.clear();
+            // Empty pieces between adjacent delimiters never count as tokens
+            for piece in regex.split(value).filter(|piece| !piece.is_empty()) {
+                let lowercased = piece.to_lowercase();
+                if piece.chars().count() < kwargs.min_token_len
+                    || (kwargs.drop_stopwords && TOKENIZE_STOP_WORDS.contains(&lowercased.as_str()))
+                {
+                    continue;
+                }
+                tokens.push(if kwargs.lowercase { lowercased } else { piece.to_string() });
+            }
+            builder.append_values_iter(tokens.iter().map(String::as_str));
+        }
+        Ok(builder.finish().into_series())
+    })
+}
+
+const MORSE_CODES: [(char, &str); 54] = [
+    ('A', ".-"), ('B', "-..."), ('C', "-.-."), ('D', "-.."), ('E', "."), ('F', "..-."), ('G', "--."), ('H', "...."),
+    ('I', ".."), ('J', ".---"), ('K', "-.-"), ('L', ".-.."), ('M', "--"), ('N', "-."), ('O', "---"), ('P', ".--."),
+    ('Q', "--.-"), ('R', ".-."), ('S', "..."), ('T', "-"), ('U', "..-"), ('V', "...-"), ('W', ".--"), ('X', "-..-"),
+    ('Y', "-.--"), ('Z', "--.."), ('0', "-----"), ('1', ".----"), ('2', "..---"), ('3', "...--"), ('4', "....-"),
+    ('5', "....."), ('6', "-...."), ('7', "--..."), ('8', "---.."), ('9', "----."), ('.', ".-.-.-"), (',', "--..--"),
+    ('?', "..--.."), ('\'', ".----."), ('!', "-.-.--"), ('/', "-..-."), ('(', "-.--."), (')', "-.--.-"), ('&', ".-..."),
+    (':', "---..."), (';', "-.-.-."), ('=', "-...-"), ('+', ".-.-."), ('-', "-....-"), ('_', "..--.-"), ('"', ".-..-."),
+    ('$', "...-..-"), ('@', ".--.-."),
+];
+
+fn default_morse_letter_sep() -> String {
+    " ".to_string()
+}
+
+fn default_morse_word_sep() -> String {
+    " / ".to_string()
+}
+
+#[derive(Deserialize)]
+pub struct MorseKwargs {
+    #[serde(default = "default_morse_letter_sep")]
+    letter_sep: String,
+    #[serde(default = "default_morse_word_sep")]
+    word_sep: String,
+    #[serde(default)]
+    skip_unsupported: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn ensure_morse_separators(kwargs: &MorseKwargs) -> PolarsResult<()> {
+    polars_ensure!(
+        !kwargs.letter_sep.is_empty() && !kwargs.word_sep.is_empty(),
+        ComputeError: "letter_sep and word_sep must not be empty"
+    );
+    polars_ensure!(
+        kwargs.letter_sep != kwargs.word_sep,
+        ComputeError: "letter_sep and word_sep must differ, got '{}' for both", kwargs.letter_sep
+    );
+    Ok(())
+}
+
+#[polars_expr(output_type=String)]
+fn morse_encode(inputs: &[Series], kwargs: MorseKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    ensure_morse_separators(&kwargs)?;
+    let out: StringChunked = par_try_apply_into_string(ca, |value: &str, output: &mut String| -> PolarsResult<()> {
+        let mut codes: Vec<&str> = Vec::new();
+        let mut is_first_word = true;
+        for word in value.split_whitespace() {
+            codes.clear();
+            for c in word.chars().flat_map(char::to_uppercase) {
+                match MORSE_CODES.iter().find(|(letter, _)| *letter == c) {
+                    Some((_, code)) => codes.push(code),
+                    None => polars_ensure!(
+                        kwargs.skip_unsupported,
+                        ComputeError: "'{}' in '{}' has no Morse code", c, value
+                    ),
+                }
+            }
+            // Words made only of skipped characters leave no trace
+            if codes.is_empty() {
+                continue;
+            }
+            if !is_first_word {
+                output.push_str(&kwargs.word_sep);
+            }
+            output.push_str(&codes.join(&kwargs.letter_sep));
+            is_first_word = false;
+        }
+        Ok(())
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn morse_decode(inputs: &[Series], kwargs: MorseKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    ensure_morse_separators(&kwargs)?;
+    let out: StringChunked = par_try_apply_into_string(ca, |value: &str, output: &mut String| -> PolarsResult<()> {
+        // Repeated separators and surrounding whitespace leave empty pieces, which are skipped
+        let words = value.split(kwargs.word_sep.as_str()).map(str::trim).filter(|word| !word.is_empty());
+        for word in words {
+            // A space only goes in once the word actually decodes to something
+            let mut needs_space = !output.is_empty();
+            for code in word.split(kwargs.letter_sep.as_str()).map(str::trim).filter(|code| !code.is_empty()) {
+                match MORSE_CODES.iter().find(|(_, known)| *known == code) {
+                    Some((letter, _)) => {
+                        if needs_space {
+                            output.push(' ');
+                            needs_space = false;
+                        }
+                        output.push(*letter);
+                    },
+                    None => polars_ensure!(
+                        kwargs.skip_unsupported,
+                        ComputeError: "'{}' in '{}' is not a known Morse code", code, value
+                    ),
+                }
+            }
+        }
+        Ok(())
+    })?;
+    Ok(out.into_series())
+}
+
+// Each substitution is paired with the lowest level that enables it
+const LEET_SUBSTITUTIONS: [(char, char, u8); 10] = [
+    ('a', '4', 1), ('e', '3', 1), ('o', '0', 1),
+    ('i', '1', 2), ('s', '5', 2), ('t', '7', 2),
+    ('b', '8', 3), ('g', '9', 3), ('l', '1', 3), ('z', '2', 3),
+];
+
+fn default_leet_level() -> u8 {
+    1
+}
+
+#[derive(Deserialize)]
+pub struct LeetKwargs {
+    #[serde(default = "default_leet_level")]
+    level: u8,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn to_leetspeak(inputs: &[Series], kwargs: LeetKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(
+        (1..=3).contains(&kwargs.level),
+        ComputeError: "level must be between 1 and 3, got {}", kwargs.level
+    );
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        output.extend(value.chars().map(|c| {
+            let lower = c.to_ascii_lowercase();
+            LEET_SUBSTITUTIONS
+                .iter()
+                .find(|(letter, _, level)| *letter == lower && *level <= kwargs.level)
+                .map_or(c, |(_, digit, _)| *digit)
+        }));
+    })?;
+    Ok(out.into_series())
+}
+
+// Printable ASCII maps onto the Halfwidth and Fullwidth Forms block at a fixed offset,
+// except for the space, whose fullwidth form is the ideographic space
+const FULLWIDTH_OFFSET: u32 = 0xFEE0;
+const IDEOGRAPHIC_SPACE: char = '\u{3000}';
+
+#[polars_expr(output_type=String)]
+fn to_fullwidth(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        output.extend(value.chars().map(|c| match c {
+            ' ' => IDEOGRAPHIC_SPACE,
+            '!'..='~' => char::from_u32(c as u32 + FULLWIDTH_OFFSET).unwrap_or(c),
+            _ => c,
+        }));
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn to_halfwidth(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        output.extend(value.chars().map(|c| match c {
+            IDEOGRAPHIC_SPACE => ' ',
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - FULLWIDTH_OFFSET).unwrap_or(c),
+            _ => c,
+        }));
+    })?;
+    Ok(out.into_series())
+}
+
+// Ambiguous contractions take their most common reading, e.g. "it's" is "it is" and "he'd" is "he would"
+const CONTRACTIONS: [(&str, &str); 79] = [
+    ("ain't", "is not"), ("aren't", "are not"), ("can't", "cannot"), ("couldn't", "could not"),
+    ("could've", "could have"), ("didn't", "did not"), ("doesn't", "does not"), ("don't", "do not"),
+    ("hadn't", "had not"), ("hasn't", "has not"), ("haven't", "have not"), ("he'd", "he would"),
+    ("he'll", "he will"), ("he's", "he is"), ("how'd", "how did"), ("how'll", "how will"),
+    ("how's", "how is"), ("i'd", "I would"), ("i'll", "I will"), ("i'm", "I am"),
+    ("i've", "I have"), ("isn't", "is not"), ("it'd", "it would"), ("it'll", "it will"),
+    ("it's", "it is"), ("let's", "let us"), ("ma'am", "madam"), ("might've", "might have"),
+    ("mightn't", "might not"), ("must've", "must have"), ("mustn't", "must not"), ("needn't", "need not"),
+    ("o'clock", "of the clock"), ("shan't", "shall not"), ("she'd", "she would"), ("she'll", "she will"),
+    ("she's", "she is"), ("should've", "should have"), ("shouldn't", "should not"), ("that'd", "that would"),
+    ("that'll", "that will"), ("that's", "that is"), ("there'd", "there would"), ("there'll", "there will"),
+    ("there's", "there is"), ("they'd", "they would"), ("they'll", "they will"), ("they're", "they are"),
+    ("they've", "they have"), ("wasn't", "was not"), ("we'd", "we would"), ("we'll", "we will"),
+    ("we're", "we are"), ("we've", "we have"), ("weren't", "were not"), ("what'll", "what will"),
+    ("what're", "what are"), ("what's", "what is"), ("what've", "what have"), ("where'd", "where did"),
+    ("where's", "where is"), ("who'd", "who would"), ("who'll", "who will"), ("who're", "who are"),
+    ("who's", "who is"), ("who've", "who have"), ("why's", "why is"), ("won't", "will not"),
+    ("would've", "would have"), ("wouldn't", "would not"), ("y'all", "you all"), ("you'd", "you would"),
+    ("you'll", "you will"), ("you're", "you are"), ("you've", "you have"), ("here's", "here is"),
+    ("when's", "when is"), ("why'd", "why did"), ("this'll", "this will"),
+];
+
+#[derive(Deserialize)]
+pub struct ContractionKwargs {
+    #[serde(default)]
+    extra: Vec<(String, String)>,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn is_apostrophe(c: char) -> bool {
+    c == '\'' || c == '\u{2019}'
+}
+
+// Writes a replacement for a word in the word's own casing
+fn push_in_case_of(word: &str, replacement: &str, output: &mut String) {
+    let mut letters = word.chars().filter(|c| c.is_alphabetic());
+    let first_is_upper = letters.next().is_some_and(char::is_uppercase);
+    let mut rest = letters.peekable();
+    // Shouted words stay shouted, capitalized ones stay capitalized
+    if first_is_upper && rest.peek().is_some() && rest.all(char::is_uppercase) {
+        output.extend(replacement.chars().flat_map(char::to_uppercase));
+    } else if first_is_upper {
+        let mut chars = replacement.chars();
+        if let Some(first_char) = chars.next() {
+            output.extend(first_char.to_uppercase());
+            output.push_str(chars.as_str());
+        }
+    } else {
+        output.push_str(replacement);
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn expand_contractions(inputs: &[Series], kwargs: ContractionKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    // User mappings are looked up first so they can override the built-in ones
+    let extra: Vec<(String, &str)> = kwargs
+        .extra
+        .iter()
+        .map(|(contraction, expansion)| (contraction.replace('\u{2019}', "'").to_lowercase(), expansion.as_str()))
+        .collect();
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        let mut rest = value;
+        while let Some(word_start) = rest.find(char::is_alphabetic) {
+            output.push_str(&rest[..word_start]);
+            rest = &rest[word_start..];
+
+            let word_end = rest.find(|c: char| !c.is_alphabetic() && !is_apostrophe(c)).unwrap_or(rest.len());
+            let word = &rest[..word_end];
+            rest = &rest[word_end..];
+
+            let key = word.replace('\u{2019}', "'").to_lowercase();
+            let expansion = extra
+                .iter()
+                .find(|(contraction, _)| *contraction == key)
+                .map(|(_, expansion)| *expansion)
+                .or_else(|| CONTRACTIONS.iter().find(|(contraction, _)| *contraction == key).map(|(_, expansion)| *expansion));
+            match expansion {
+                Some(expansion) => push_in_case_of(word, expansion, output),
+                None => output.push_str(word),
+            }
+        }
+        output.push_str(rest);
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct QuoteKwargs {
+    #[serde(default = "default_true")]
+    to_straight: bool,
+    #[serde(default)]
+    dashes: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+// Quotes open at the start of a string, after whitespace and after opening brackets
+fn opens_quote(previous: Option<char>) -> bool {
+    previous.is_none_or(|c| c.is_whitespace() || matches!(c, '(' | '[' | '{' | '\u{201C}' | '\u{2018}'))
+}
+
+#[polars_expr(output_type=String)]
+fn normalize_quotes(inputs: &[Series], kwargs: QuoteKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        if kwargs.to_straight {
+            output.extend(value.chars().map(|c| match c {
+                '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' => '"',
+                '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' => '\'',
+                '\u{2013}' | '\u{2014}' if kwargs.dashes => '-',
+                _ => c,
+            }));
+            return;
+        }
+
+        let mut chars = value.chars().peekable();
+        let mut previous: Option<char> = None;
+        while let Some(c) = chars.next() {
+            let next = chars.peek().copied();
+            let converted = match c {
+                '"' if opens_quote(previous) => '\u{201C}',
+                '"' => '\u{201D}',
+                // Apostrophes inside words use the closing single quote, as typographers do
+                '\'' if opens_quote(previous) => '\u{2018}',
+                '\'' => '\u{2019}',
+                '-' if kwargs.dashes && next == Some('-') => {
+                    chars.next();
+                    '\u{2014}'
+                },
+                '-' if kwargs.dashes && previous == Some(' ') && next == Some(' ') => '\u{2013}',
+                _ => c,
+            };
+            output.push(converted);
+            previous = Some(converted);
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+const CHAR_STATS_FIELDS: [&str; 5] = ["letters", "digits", "whitespace", "punctuation", "other"];
+
+fn char_stats_output(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let fields = CHAR_STATS_FIELDS.iter().map(|name| Field::new((*name).into(), DataType::UInt32)).collect();
+    Ok(Field::new(field.name().clone(), DataType::Struct(fields)))
+}
+
+#[polars_expr(output_type_func=char_stats_output)]
+fn char_stats(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    // Unicode general categories; whatever none of them match counts as other
+    let categories = [r"\p{L}", r"\p{Nd}", r"\s", r"\p{P}"]
+        .iter()
+        .map(|pattern| compile_regex(pattern, false))
+        .collect::<PolarsResult<Vec<Regex>>>()?;
+    par_map_slices(ca, |ca| {
+        let mut counts: Vec<Vec<Option<u32>>> = vec![Vec::with_capacity(ca.len()); CHAR_STATS_FIELDS.len()];
+        for value in ca.iter() {
+            let Some(value) = value else {
+                counts.iter_mut().for_each(|field| field.push(None));
+                continue;
+            };
+            let mut categorized = 0;
+            for (field, category) in counts.iter_mut().zip(&categories) {
+                let count = category.find_iter(value).count() as u32;
+                categorized += count;
+                field.push(Some(count));
+            }
+            counts[CHAR_STATS_FIELDS.len() - 1].push(Some(value.chars().count() as u32 - categorized));
+        }
+        let fields: Vec<Series> = CHAR_STATS_FIELDS
+            .iter()
+            .zip(&counts)
+            .map(|(name, field)| Series::new((*name).into(), field))
+            .collect();
+        // Null rows are null structs, not structs of nulls
+        let out = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
+        Ok(out.with_outer_validity(ca.rechunk_validity()).into_series())
+    })
+}
+
+#[derive(Deserialize)]
+pub struct VowelKwargs {
+    #[serde(default)]
+    treat_y_as_vowel: bool,
+    #[serde(default)]
+    ascii_only: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=UInt32)]
+fn count_vowels(inputs: &[Series], kwargs: VowelKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: UInt32Chunked = par_apply_nonnull_values_generic(ca, DataType::UInt32, |value: &str| {
+        value.chars().filter(|&c| is_vowel_letter(c, kwargs.treat_y_as_vowel, kwargs.ascii_only)).count() as u32
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=UInt32)]
+fn count_consonants(inputs: &[Series], kwargs: VowelKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: UInt32Chunked = par_apply_nonnull_values_generic(ca, DataType::UInt32, |value: &str| {
+        value.chars().filter(|&c| is_consonant_letter(c, kwargs.treat_y_as_vowel, kwargs.ascii_only)).count() as u32
+    })?;
+    Ok(out.into_series())
+}
+
+// Words are runs of letters, with apostrophes kept inside so "don't" stays one word
+fn split_into_english_words(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(|c: char| !c.is_alphabetic() && !is_apostrophe(c))
+        .map(|word| word.trim_matches(is_apostrophe))
+        .filter(|word| !word.is_empty())
+}
+
+// Counts vowel groups, with 'y' as a vowel past the first letter, then drops a silent final 'e'
+// as in "cake" but not in "free", "table" or "café". Every word has at least one syllable.
+fn estimate_syllables(word: &str) -> u32 {
+    let letters: Vec<char> = word
+        .chars()
+        .filter_map(|c| latin_base_letter(c, false))
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    let is_syllable_vowel = |i: usize| is_vowel(letters[i]) || (i > 0 && letters[i] == 'y');
+
+    let mut count = 0;
+    let mut in_vowel_group = false;
+    for i in 0..letters.len() {
+        let is_vowel_here = is_syllable_vowel(i);
+        if is_vowel_here && !in_vowel_group {
+            count += 1;
+        }
+        in_vowel_group = is_vowel_here;
+    }
+
+    let n = letters.len();
+    if count > 1 && n >= 2 && word.ends_with(['e', 'E']) && !is_syllable_vowel(n - 2) {
+        let is_consonant_le = letters[n - 2] == 'l' && n >= 3 && !is_syllable_vowel(n - 3);
+        if !is_consonant_le {
+            count -= 1;
+        }
+    }
+    count.max(1)
+}
+
+#[derive(Deserialize)]
+pub struct SyllableKwargs {
+    #[serde(default)]
+    per_word: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn syllable_count_output(input_fields: &[Field], kwargs: SyllableKwargs) -> PolarsResult<Field> {
+    if kwargs.per_word {
+        return list_uint32_output(input_fields);
+    }
+    let field = &input_fields[0];
+    Ok(Field::new(field.name().clone(), DataType::UInt32))
+}
+
+#[polars_expr(output_type_func_with_kwargs=syllable_count_output)]
+fn syllable_count(inputs: &[Series], kwargs: SyllableKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    if !kwargs.per_word {
+        let out: UInt32Chunked = par_apply_nonnull_values_generic(ca, DataType::UInt32, |value: &str| {
+            split_into_english_words(value).map(estimate_syllables).sum::<u32>()
+        })?;
+        return Ok(out.into_series());
+    }
+    par_map_slices(ca, |ca| {
+        let mut builder =
+            ListPrimitiveChunkedBuilder::<UInt32Type>::new(ca.name().clone(), ca.len(), ca.len(), DataType::UInt32);
+        for value in ca.iter() {
+            match value {
+                Some(value) => builder.append_values_iter(split_into_english_words(value).map(estimate_syllables)),
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into_series())
+    })
+}
+
+// Text without a single word has no meaningful score
+fn flesch_reading_ease_score(value: &str) -> Option<f64> {
+    let mut sentences = 0;
+    let mut words = 0;
+    let mut syllables = 0;
+    // Trailing text without an ender still makes a sentence; runs of enders such as "?!" do not
+    for sentence in value.split(is_sentence_ender) {
+        let sentence_words = split_into_english_words(sentence).map(estimate_syllables);
+        let (word_count, syllable_count) = sentence_words.fold((0, 0), |(w, s), n| (w + 1, s + n));
+        if word_count > 0 {
+            sentences += 1;
+            words += word_count;
+            syllables += syllable_count;
+        }
+    }
+    if words == 0 {
+        return None;
+    }
+    let words_per_sentence = words as f64 / sentences as f64;
+    let syllables_per_word = syllables as f64 / words as f64;
+    Some(206.835 - 1.015 * words_per_sentence - 84.6 * syllables_per_word)
+}
+
+#[polars_expr(output_type=Float64)]
+fn flesch_reading_ease(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    par_map_slices(ca, |ca| {
+        let out: Float64Chunked = ca.iter().map(|value| value.and_then(flesch_reading_ease_score)).collect();
+        Ok(out.with_name(ca.name().clone()).into_series())
+    })
+}
+
+// Scripts not listed here still count towards the total, under "other"
+const SCRIPTS: [&str; 16] = [
+    "Latin", "Greek", "Cyrillic", "Armenian", "Hebrew", "Arabic", "Devanagari", "Bengali", "Tamil", "Thai",
+    "Georgian", "Ethiopic", "Hangul", "Hiragana", "Katakana", "Han",
+];
+
+fn default_script_threshold() -> f64 {
+    0.6
+}
+
+#[derive(Deserialize)]
+pub struct ScriptKwargs {
+    #[serde(default = "default_script_threshold")]
+    threshold: f64,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn detect_script(inputs: &[Series], kwargs: ScriptKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(
+        (0.0..=1.0).contains(&kwargs.threshold),
+        ComputeError: "threshold must be within [0, 1], got {}", kwargs.threshold
+    );
+    // Only letters vote, so digits, punctuation and emoji never tip the balance
+    let letters = compile_regex(r"\p{L}", false)?;
+    let scripts = SCRIPTS
+        .iter()
+        .map(|script| Ok((*script, compile_regex(&format!(r"[\p{{L}}&&\p{{Script={}}}]", script), false)?)))
+        .collect::<PolarsResult<Vec<(&str, Regex)>>>()?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        let total = letters.find_iter(value).count();
+        if total == 0 {
+            output.push_str("unknown");
+            return;
+        }
+        let mut dominant = ("other", 0);
+        let mut counted = 0;
+        for (script, regex) in &scripts {
+            let count = regex.find_iter(value).count();
+            if count > dominant.1 {
+                dominant = (script, count);
+            }
+            counted += count;
+            if counted == total {
+                break;
+            }
+        }
+        if total - counted > dominant.1 {
+            dominant = ("other", total - counted);
+        }
+        if (dominant.1 as f64 / total as f64) < kwargs.threshold {
+            output.push_str("mixed");
+        } else {
+            output.push_str(dominant.0);
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+// Separated groups such as "555-123-4567", "(555) 123 4567" or "+44 20 7946 0958"
+const PHONE_PATTERN: &str = r"(?:\+\d{1,3}[ .-]?)?(?:\(\d{2,4}\)[ .-]?|\b\d{2,4}[ .-])\d{3,4}[ .-]?\d{3,4}\b";
+const SSN_PATTERN: &str = r"\b\d{3}-\d{2}-\d{4}\b";
+
+#[derive(Deserialize)]
+pub struct PiiKwargs {
+    #[serde(default = "default_true")]
+    redact_emails: bool,
+    #[serde(default = "default_true")]
+    redact_phones: bool,
+    #[serde(default = "default_true")]
+    redact_ssn: bool,
+    #[serde(default = "default_mask_char")]
+    mask_char: String,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn redact_pii(inputs: &[Series], kwargs: PiiKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(!kwargs.mask_char.is_empty(), ComputeError: "mask_char must not be empty");
+    // One alternation yields non-overlapping spans from left to right, trying emails before the
+    // looser number shapes so digits inside an address are not redacted on their own
+    let patterns: Vec<String> = [
+        (kwargs.redact_emails, EMAIL_PATTERN),
+        (kwargs.redact_ssn, SSN_PATTERN),
+        (kwargs.redact_phones, PHONE_PATTERN),
+    ]
+    .iter()
+    .filter(|(enabled, _)| *enabled)
+    .map(|(_, pattern)| format!("(?:{})", pattern))
+    .collect();
+    if patterns.is_empty() {
+        return Ok(ca.clone().into_series());
+    }
+    let regex = compile_regex(&patterns.join("|"), false)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        let mut last_end = 0;
+        for m in regex.find_iter(value) {
+            output.push_str(&value[last_end..m.start()]);
+            push_masked(m.as_str(), 0, 0, &kwargs.mask_char, output);
+            last_end = m.end();
+        }
+        output.push_str(&value[last_end..]);
+    })?;
+    Ok(out.into_series())
+}
+
+fn default_max_distance() -> usize {
+    1
+}
+
+#[derive(Deserialize)]
+pub struct FuzzyKwargs {
+    needle: String,
+    #[serde(default = "default_max_distance")]
+    max_distance: usize,
+    #[serde(default)]
+    case_insensitive: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+// Approximate substring search after Sellers, where a match may start anywhere in the text.
+// Costs are capped at max_distance + 1 and only needle positions up to the last one still within
+// max_distance are updated, so each text character costs O(max_distance) on average.
+fn fuzzy_find(needle: &[char], text: impl Iterator<Item = char>, max_distance: usize) -> bool {
+    let m = needle.len();
+    let cap = max_distance + 1;
+    let mut costs: Vec<usize> = (0..=m).map(|i| i.min(cap)).collect();
+    let mut last_active = max_distance.min(m);
+    if last_active == m {
+        return true;
+    }
+    for c in text {
+        let mut diagonal = 0;
+        let last_computed = (last_active + 1).min(m);
+        for i in 1..=last_computed {
+            // The row just past the band has no valid cost yet
+            let above = if i > last_active { cap } else { costs[i] };
+            let substitution = diagonal + usize::from(needle[i - 1] != c);
+            costs[i] = substitution.min(above + 1).min(costs[i - 1] + 1).min(cap);
+            diagonal = above;
+        }
+        if costs[last_computed] <= max_distance {
+            last_active = last_computed;
+        } else {
+            while last_active > 0 && costs[last_active] > max_distance {
+                last_active -= 1;
+            }
+        }
+        if last_active == m {
+            return true;
+        }
+    }
+    false
+}
+
+#[polars_expr(output_type=Boolean)]
+fn fuzzy_contains(inputs: &[Series], kwargs: FuzzyKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(!kwargs.needle.is_empty(), ComputeError: "needle must not be empty");
+    let needle: Vec<char> = if kwargs.case_insensitive {
+        kwargs.needle.chars().flat_map(char::to_lowercase).collect()
+    } else {
+        kwargs.needle.chars().collect()
+    };
+    let out: BooleanChunked = par_apply_nonnull_values_generic(ca, DataType::Boolean, |value: &str| {
+        if kwargs.case_insensitive {
+            fuzzy_find(&needle, value.chars().flat_map(char::to_lowercase), kwargs.max_distance)
+        } else {
+            fuzzy_find(&needle, value.chars(), kwargs.max_distance)
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+// Unlike the case converters, which keep "JSON2" together, digits here always form their own word
+fn push_digit_runs<'a>(word: &'a str, words: &mut Vec<&'a str>) {
+    let mut start = 0;
+    let mut previous_is_digit: Option<bool> = None;
+    for (i, c) in word.char_indices() {
+        let is_digit = c.is_numeric();
+        if previous_is_digit.is_some_and(|previous| previous != is_digit) {
+            words.push(&word[start..i]);
+            start = i;
+        }
+        previous_is_digit = Some(is_digit);
+    }
+    words.push(&word[start..]);
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn split_words(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    par_map_slices(ca, |ca| {
+        let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.get_values_size());
+        for value in ca.iter() {
+            let Some(value) = value else {
+                builder.append_null();
+                continue;
+            };
+            let words = split_into_words(value);
+            let mut parts: Vec<&str> = Vec::with_capacity(words.len());
+            for word in &words {
+                push_digit_runs(word, &mut parts);
+            }
+            builder.append_values_iter(parts.iter().copied());
+        }
+        Ok(builder.finish().into_series())
+    })
+}
+
+#[derive(Deserialize)]
+pub struct SearchKeyKwargs {
+    #[serde(default = "default_true")]
+    normalize: bool,
+    #[serde(default = "default_true")]
+    remove_diacritics: bool,
+    #[serde(default = "default_true")]
+    case_fold: bool,
+    #[serde(default = "default_true")]
+    squeeze_whitespace: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn search_key(inputs: &[Series], kwargs: SearchKeyKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        // NFKC is NFKD followed by recomposition, so stripping marks in between covers both stages
+        let chars: Box<dyn Iterator<Item = char>> = match (kwargs.normalize, kwargs.remove_diacritics) {
+            (true, true) => Box::new(value.nfkd().filter(|&c| !is_diacritic_mark(c)).nfc()),
+            (true, false) => Box::new(value.nfkc()),
+            (false, true) => Box::new(value.nfd().filter(|&c| !is_diacritic_mark(c)).nfc()),
+            (false, false) => Box::new(value.chars()),
+        };
+
+        // Whitespace runs collapse into one space, and leading or trailing ones are dropped
+        let mut pending_space = false;
+        let mut push = |c: char| {
+            if kwargs.squeeze_whitespace && c.is_whitespace() {
+                pending_space = !output.is_empty();
+                return;
+            }
+            if pending_space {
+                output.push(' ');
+                pending_space = false;
+            }
+            output.push(c);
+        };
+
+        for c in chars {
+            if !kwargs.case_fold {
+                push(c);
+                continue;
+            }
+            // Same folding as push_case_folded, one character at a time
+            for c in c.to_lowercase() {
+                if c == 'ı' {
+                    push(c);
+                } else {
+                    c.to_uppercase().flat_map(char::to_lowercase).for_each(&mut push);
+                }
+            }
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+// Singular and plural forms that no suffix rule produces
+const IRREGULAR_NOUNS: [(&str, &str); 47] = [
+    ("child", "children"), ("person", "people"), ("man", "men"), ("woman", "women"), ("mouse", "mice"),
+    ("louse", "lice"), ("goose", "geese"), ("foot", "feet"), ("tooth", "teeth"), ("ox", "oxen"),
+    ("die", "dice"), ("index", "indices"), ("matrix", "matrices"), ("vertex", "vertices"),
+    ("appendix", "appendices"), ("cactus", "cacti"), ("focus", "foci"), ("fungus", "fungi"),
+    ("nucleus", "nuclei"), ("radius", "radii"), ("stimulus", "stimuli"), ("analysis", "analyses"),
+    ("crisis", "crises"), ("diagnosis", "diagnoses"), ("thesis", "theses"),
+    ("criterion", "criteria"), ("phenomenon", "phenomena"), ("datum", "data"), ("medium", "media"),
+    ("curriculum", "curricula"), ("leaf", "leaves"), ("knife", "knives"), ("life", "lives"),
+    ("wife", "wives"), ("half", "halves"), ("wolf", "wolves"), ("shelf", "shelves"), ("thief", "thieves"),
+    ("potato", "potatoes"), ("tomato", "tomatoes"), ("hero", "heroes"), ("echo", "echoes"),
+    ("quiz", "quizzes"), ("bus", "buses"), ("status", "statuses"), ("movie", "movies"), ("cookie", "cookies"),
+];
+
+const UNCOUNTABLE_NOUNS: [&str; 18] = [
+    "sheep", "fish", "deer", "series", "species", "moose", "bison", "salmon", "trout", "swine", "aircraft",
+    "information", "rice", "money", "news", "equipment", "software", "furniture",
+];
+
+#[derive(Deserialize)]
+pub struct InflectKwargs {
+    #[serde(default)]
+    extra_irregulars: Vec<(String, String)>,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn pluralize_by_rule(word: &str) -> String {
+    let ends_with_consonant_y =
+        word.len() > 1 && word.ends_with('y') && !word[..word.len() - 1].ends_with(|c: char| is_vowel(c));
+    if ends_with_consonant_y {
+        format!("{}ies", &word[..word.len() - 1])
+    } else if word.ends_with(['s', 'x', 'z']) || word.ends_with("ch") || word.ends_with("sh") {
+        format!("{}es", word)
+    } else {
+        format!("{}s", word)
+    }
+}
+
+fn singularize_by_rule(word: &str) -> String {
+    // Singular words that merely end in "s", like "class", "virus" or "axis"
+    if word.ends_with("ss") || word.ends_with("us") || word.ends_with("is") || !word.ends_with('s') {
+        return word.to_string();
+    }
+    if word.len() > 3 && word.ends_with("ies") {
+        return format!("{}y", &word[..word.len() - 3]);
+    }
+    let sibilant_es = ["sses", "ches", "shes", "xes", "zzes"];
+    if sibilant_es.iter().any(|suffix| word.ends_with(suffix)) {
+        return word[..word.len() - 2].to_string();
+    }
+    word[..word.len() - 1].to_string()
+}
+
+// Only the trailing word is inflected, so "bus stop" becomes "bus stops"
+fn push_inflected(value: &str, irregulars: &[(String, String)], to_plural: bool, output: &mut String) {
+    let word_start = value
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_alphabetic())
+        .last()
+        .map_or(value.len(), |(i, _)| i);
+    let (prefix, word) = value.split_at(word_start);
+    output.push_str(prefix);
+    if word.is_empty() {
+        return;
+    }
+    let lower = word.to_lowercase();
+    if UNCOUNTABLE_NOUNS.contains(&lower.as_str()) {
+        output.push_str(word);
+        return;
+    }
+    // Words already in the requested form are left alone
+    let irregular = irregulars
+        .iter()
+        .find(|(singular, plural)| *singular == lower || *plural == lower)
+        .map(|(singular, plural)| if to_plural { plural } else { singular });
+    let inflected = match irregular {
+        Some(irregular) => irregular.to_string(),
+        None if to_plural => pluralize_by_rule(&lower),
+        None => singularize_by_rule(&lower),
+    };
+    push_in_case_of(word, &inflected, output);
+}
+
+// User irregulars come first so they can override the built-in ones
+fn collect_irregulars(kwargs: &InflectKwargs) -> Vec<(String, String)> {
+    kwargs
+        .extra_irregulars
+        .iter()
+        .map(|(singular, plural)| (singular.to_lowercase(), plural.to_lowercase()))
+        .chain(IRREGULAR_NOUNS.iter().map(|(singular, plural)| (singular.to_string(), plural.to_string())))
+        .collect()
+}
+
+#[polars_expr(output_type=String)]
+fn pluralize(inputs: &[Series], kwargs: InflectKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let irregulars = collect_irregulars(&kwargs);
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        push_inflected(value, &irregulars, true, output);
+    })?;
+    Ok(out.into_series())
+}
+
+#[polars_expr(output_type=String)]
+fn singularize(inputs: &[Series], kwargs: InflectKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let irregulars = collect_irregulars(&kwargs);
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        push_inflected(value, &irregulars, false, output);
+    })?;
+    Ok(out.into_series())
+}
+
+// Names that only look like a "Mac" prefix
+const MAC_EXCEPTIONS: [&str; 12] = [
+    "machado", "machin", "machine", "macias", "mackie", "macklin", "macon", "maceo", "macey", "macedo",
+    "machek", "machen",
+];
+
+#[derive(Deserialize)]
+pub struct NameCaseKwargs {
+    #[serde(default = "default_true")]
+    mc_rule: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn push_name_part(part: &str, follows_apostrophe: bool, mc_rule: bool, output: &mut String) {
+    let lower = part.to_lowercase();
+    // A single letter after an apostrophe is a possessive or a contraction, as in "John's"
+    if follows_apostrophe && lower.chars().nth(1).is_none() {
+        output.push_str(&lower);
+        return;
+    }
+    if mc_rule {
+        let prefix = if lower.starts_with("mc") && lower.chars().count() > 2 {
+            Some("Mc")
+        } else if lower.starts_with("mac") && lower.chars().count() > 5 && !MAC_EXCEPTIONS.contains(&lower.as_str()) {
+            Some("Mac")
+        } else {
+            None
+        };
+        if let Some(prefix) = prefix {
+            output.push_str(prefix);
+            push_capitalized(&lower[prefix.len()..], output);
+            return;
+        }
+    }
+    push_capitalized(&lower, output);
+}
+
+#[polars_expr(output_type=String)]
+fn to_name_case(inputs: &[Series], kwargs: NameCaseKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        // Every run of letters starts a new part, so "o'brien" and "smith-jones" get two capitals
+        let mut rest = value;
+        while let Some(part_start) = rest.find(char::is_alphabetic) {
+            let follows_apostrophe = rest[..part_start].ends_with(is_apostrophe);
+            output.push_str(&rest[..part_start]);
+            rest = &rest[part_start..];
+
+            let part_end = rest.find(|c: char| !c.is_alphabetic()).unwrap_or(rest.len());
+            push_name_part(&rest[..part_end], follows_apostrophe, kwargs.mc_rule, output);
+            rest = &rest[part_end..];
+        }
+        output.push_str(rest);
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct AltCaseKwargs {
+    #[serde(default)]
+    start_upper: bool,
+    #[serde(default)]
+    skip_nonalpha: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn to_alternating_case(inputs: &[Series], kwargs: AltCaseKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        let mut upper = kwargs.start_upper;
+        for c in value.chars() {
+            if c.is_alphabetic() {
+                if upper {
+                    output.extend(c.to_uppercase());
+                } else {
+                    output.extend(c.to_lowercase());
+                }
+            } else {
+                output.push(c);
+                // Non-letters still take a turn unless they are skipped
+                if kwargs.skip_nonalpha {
+                    continue;
+                }
+            }
+            upper = !upper;
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct StripAccentsKwargs {
+    #[serde(default)]
+    preserve: Vec<String>,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn strip_accents(inputs: &[Series], kwargs: StripAccentsKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let preserve = kwargs
+        .preserve
+        .iter()
+        .map(|entry| {
+            let mut chars = entry.nfc();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(c),
+                _ => polars_bail!(ComputeError: "preserve entries must be single characters, got '{}'", entry),
+            }
+        })
+        .collect::<PolarsResult<Vec<char>>>()?;
+    // Preserved characters keep their accents in either case, so "ñ" also covers "Ñ"
+    let is_preserved = |c: char| preserve.iter().any(|&p| p == c || p.to_lowercase().eq(c.to_lowercase()));
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        // Compose first so a decomposed "n" + tilde is recognized as one preserved "ñ"
+        output.extend(
+            value
+                .nfc()
+                .flat_map(|c| {
+                    let keep_marks = is_preserved(c);
+                    std::iter::once(c).nfd().filter(move |&m| keep_marks || !is_diacritic_mark(m))
+                })
+                .nfc(),
+        );
+    })?;
+    Ok(out.into_series())
+}
+
+fn default_fit_align() -> String {
+    "left".to_string()
+}
+
+fn default_fit_fill() -> String {
+    " ".to_string()
+}
+
+#[derive(Deserialize)]
+pub struct FitKwargs {
+    width: usize,
+    #[serde(default = "default_fit_align")]
+    align: String,
+    #[serde(default = "default_fit_fill")]
+    fill: String,
+    #[serde(default)]
+    truncate: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn fit_width(inputs: &[Series], kwargs: FitKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(
+        matches!(kwargs.align.as_str(), "left" | "right" | "center"),
+        ComputeError: "align must be 'left', 'right' or 'center', got '{}'", kwargs.align
+    );
+    // A wider fill could not land on the width exactly
+    polars_ensure!(
+        kwargs.fill.graphemes(true).count() == 1,
+        ComputeError: "fill must be a single character, got '{}'", kwargs.fill
+    );
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        // Widths are counted in grapheme clusters, as a reader would
+        let len = value.graphemes(true).count();
+        if len >= kwargs.width {
+            if kwargs.truncate {
+                let cut = value.grapheme_indices(true).nth(kwargs.width).map_or(value.len(), |(i, _)| i);
+                output.push_str(&value[..cut]);
+            } else {
+                output.push_str(value);
+            }
+            return;
+        }
+        let padding = kwargs.width - len;
+        let left = match kwargs.align.as_str() {
+            "left" => 0,
+            "right" => padding,
+            _ => padding / 2,
+        };
+        output.push_str(&kwargs.fill.repeat(left));
+        output.push_str(value);
+        output.push_str(&kwargs.fill.repeat(padding - left));
+    })?;
+    Ok(out.into_series())
+}
+
+fn default_keyword_count() -> usize {
+    5
+}
+
+fn default_keyword_stopwords() -> Vec<String> {
+    TOKENIZE_STOP_WORDS.iter().map(|word| word.to_string()).collect()
+}
+
+fn default_keyword_min_len() -> usize {
+    3
+}
+
+#[derive(Deserialize)]
+pub struct KeywordKwargs {
+    #[serde(default = "default_keyword_count")]
+    n: usize,
+    #[serde(default = "default_keyword_stopwords")]
+    stopwords: Vec<String>,
+    #[serde(default = "default_keyword_min_len")]
+    min_len: usize,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn top_keywords(inputs: &[Series], kwargs: KeywordKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(kwargs.n > 0, ComputeError: "n must be greater than zero");
+    let stopwords: Vec<String> = kwargs.stopwords.iter().map(|word| word.to_lowercase()).collect();
+    let regex = compile_regex(r"\W+", false)?;
+    par_map_slices(ca, |ca| {
+        let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.get_values_size());
+        for value in ca.iter() {
+            let Some(value) = value else {
+                builder.append_null();
+                continue;
+            };
+            let mut tokens: Vec<String> = regex
+                .split(value)
+                .map(str::to_lowercase)
+                .filter(|token| token.chars().count() >= kwargs.min_len.max(1) && !stopwords.contains(token))
+                .collect();
+            // Sorting groups equal tokens into runs, which are then ranked by frequency
+            tokens.sort_unstable();
+            let mut counts: Vec<(&str, usize)> = Vec::new();
+            for token in &tokens {
+                match counts.last_mut() {
+                    Some((last, count)) if *last == token.as_str() => *count += 1,
+                    _ => counts.push((token, 1)),
+                }
+            }
+            // Ties break alphabetically so the output does not depend on word order
+            counts.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then_with(|| a.cmp(b)));
+            builder.append_values_iter(counts.iter().take(kwargs.n).map(|(token, _)| *token));
+        }
+        Ok(builder.finish().into_series())
+    })
+}
+
+fn default_country_code() -> String {
+    "1".to_string()
+}
+
+fn default_phone_format() -> String {
+    "e164".to_string()
+}
+
+#[derive(Deserialize)]
+pub struct PhoneKwargs {
+    #[serde(default = "default_country_code")]
+    default_country_code: String,
+    #[serde(default = "default_phone_format")]
+    output_format: String,
+    #[serde(default)]
+    strict: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+// E.164 numbers have at most 15 digits; fewer than 8 cannot be a full number anywhere
+const PHONE_DIGITS_RANGE: std::ops::RangeInclusive<usize> = 8..=15;
+
+fn normalize_phone_number(value: &str, country_code: &str) -> Option<String> {
+    let trimmed = value.trim();
+    if trimmed.contains(char::is_alphabetic) {
+        return None;
+    }
+    let digits: String = trimmed.chars().filter(char::is_ascii_digit).collect();
+    // "+" and the "00" exit code both mark a number that already carries its country code
+    let mut number = if trimmed.starts_with('+') {
+        digits
+    } else if let Some(international) = digits.strip_prefix("00") {
+        international.to_string()
+    } else if digits.starts_with(country_code) && digits.len() - country_code.len() >= 10 {
+        // Long enough to hold the country code and a full national number, as in "1 555 123 4567"
+        digits
+    } else {
+        // The national trunk prefix, as in the UK's "07946 ...", is dropped before the country code
+        format!("{}{}", country_code, digits.strip_prefix('0').unwrap_or(&digits))
+    };
+    if !PHONE_DIGITS_RANGE.contains(&number.len()) {
+        return None;
+    }
+    number.insert(0, '+');
+    Some(number)
+}
+
+#[polars_expr(output_type=String)]
+fn normalize_phone(inputs: &[Series], kwargs: PhoneKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(
+        (1..=3).contains(&kwargs.default_country_code.len())
+            && kwargs.default_country_code.chars().all(|c| c.is_ascii_digit()),
+        ComputeError: "default_country_code must be 1 to 3 digits, got '{}'", kwargs.default_country_code
+    );
+    polars_ensure!(
+        matches!(kwargs.output_format.as_str(), "e164" | "digits"),
+        ComputeError: "output_format must be 'e164' or 'digits', got '{}'", kwargs.output_format
+    );
+    par_map_slices(ca, |ca| {
+        let out: StringChunked = ca
+            .iter()
+            .map(|value| -> PolarsResult<Option<String>> {
+                let Some(value) = value else {
+                    return Ok(None);
+                };
+                match normalize_phone_number(value, &kwargs.default_country_code) {
+                    Some(number) if kwargs.output_format == "digits" => Ok(Some(number[1..].to_string())),
+                    Some(number) => Ok(Some(number)),
+                    // Make invalid values null unless in strict mode
+                    None if !kwargs.strict => Ok(None),
+                    None => polars_bail!(ComputeError: "'{}' is not a valid phone number", value),
+                }
+            })
+            .collect::<PolarsResult<StringChunked>>()?;
+        Ok(out.with_name(ca.name().clone()).into_series())
+    })
+}
+
+const TRUTHY_STRINGS: [&str; 6] = ["true", "t", "yes", "y", "1", "on"];
+const FALSY_STRINGS: [&str; 6] = ["false", "f", "no", "n", "0", "off"];
+
+#[derive(Deserialize)]
+pub struct ParseBoolKwargs {
+    #[serde(default)]
+    strict: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=Boolean)]
+fn parse_bool(inputs: &[Series], kwargs: ParseBoolKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    par_map_slices(ca, |ca| {
+        let out: BooleanChunked = ca
+            .iter()
+            .map(|value| -> PolarsResult<Option<bool>> {
+                let Some(value) = value else {
+                    return Ok(None);
+                };
+                let lowercased = value.trim().to_lowercase();
+                if TRUTHY_STRINGS.contains(&lowercased.as_str()) {
+                    Ok(Some(true))
+                } else if FALSY_STRINGS.contains(&lowercased.as_str()) {
+                    Ok(Some(false))
+                } else if !kwargs.strict {
+                    // Make unrecognized values null unless in strict mode
+                    Ok(None)
+                } else {
+                    polars_bail!(ComputeError: "'{}' is not a recognized boolean value", value)
+                }
+            })
+            .collect::<PolarsResult<BooleanChunked>>()?;
+        Ok(out.with_name(ca.name().clone()).into_series())
+    })
+}
+
+fn default_hash_algorithm() -> String {
+    "sha256".to_string()
+}
+
+fn default_hash_output() -> String {
+    "hex".to_string()
+}
+
+#[derive(Deserialize)]
+pub struct HashKwargs {
+    #[serde(default = "default_hash_algorithm")]
+    algorithm: String,
+    #[serde(default = "default_hash_output")]
+    output: String,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn hash_string_output(input_fields: &[Field], kwargs: HashKwargs) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let dtype = if kwargs.output == "int" { DataType::UInt64 } else { DataType::String };
+    Ok(Field::new(field.name().clone(), dtype))
+}
+
+// The integer output keeps the leading 64 bits of the digest
+fn hash_to_u64(value: &str, algorithm: &str) -> u64 {
+    match algorithm {
+        "xxh3" => xxh3_64(value.as_bytes()),
+        _ => {
+            let digest = Sha256::digest(value.as_bytes());
+            let mut prefix = [0u8; 8];
+            prefix.copy_from_slice(&digest[..8]);
+            u64::from_be_bytes(prefix)
+        },
+    }
+}
+
+#[polars_expr(output_type_func_with_kwargs=hash_string_output)]
+fn hash_string(inputs: &[Series], kwargs: HashKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(
+        matches!(kwargs.algorithm.as_str(), "sha256" | "xxh3"),
+        ComputeError: "algorithm must be 'sha256' or 'xxh3', got '{}'", kwargs.algorithm
+    );
+    polars_ensure!(
+        matches!(kwargs.output.as_str(), "hex" | "int"),
+        ComputeError: "output must be 'hex' or 'int', got '{}'", kwargs.output
+    );
+    if kwargs.output == "int" {
+        let out: UInt64Chunked = par_apply_nonnull_values_generic(ca, DataType::UInt64, |value: &str| {
+            hash_to_u64(value, &kwargs.algorithm)
+        })?;
+        return Ok(out.into_series());
+    }
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        if kwargs.algorithm == "xxh3" {
+            output.push_str(&format!("{:016x}", xxh3_64(value.as_bytes())));
+        } else {
+            output.push_str(&hex::encode(Sha256::digest(value.as_bytes())));
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+// Characters that Windows-1252 puts in place of the C1 control codes 0x80 to 0x9F
+const CP1252_SPECIALS: [(char, u8); 27] = [
+    ('€', 0x80), ('‚', 0x82), ('ƒ', 0x83), ('„', 0x84), ('…', 0x85), ('†', 0x86), ('‡', 0x87),
+    ('ˆ', 0x88), ('‰', 0x89), ('Š', 0x8A), ('‹', 0x8B), ('Œ', 0x8C), ('Ž', 0x8E), ('‘', 0x91),
+    ('’', 0x92), ('“', 0x93), ('”', 0x94), ('•', 0x95), ('–', 0x96), ('—', 0x97), ('˜', 0x98),
+    ('™', 0x99), ('š', 0x9A), ('›', 0x9B), ('œ', 0x9C), ('ž', 0x9E), ('Ÿ', 0x9F),
+];
+
+// The byte a character would have come from if UTF-8 text was misread as Windows-1252,
+// falling back to Latin-1 for the control codes Windows-1252 leaves undefined
+fn cp1252_byte(c: char) -> Option<u8> {
+    match c as u32 {
+        0..=0xFF => Some(c as u8),
+        _ => CP1252_SPECIALS.iter().find(|(special, _)| *special == c).map(|(_, byte)| *byte),
+    }
+}
+
+// Re-encodes the whole value and keeps the result only if it is valid UTF-8
+fn repair_mojibake(value: &str) -> Option<String> {
+    let bytes = value.chars().map(cp1252_byte).collect::<Option<Vec<u8>>>()?;
+    String::from_utf8(bytes).ok()
+}
+
+// Repairs every misread UTF-8 sequence on its own, leaving the text around it alone
+fn repair_mojibake_sequences(value: &str, output: &mut String) -> bool {
+    let chars: Vec<char> = value.chars().collect();
+    let mut repaired = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let length = match cp1252_byte(chars[i]) {
+            Some(0xC2..=0xDF) => 2,
+            Some(0xE0..=0xEF) => 3,
+            Some(0xF0..=0xF4) => 4,
+            _ => 1,
+        };
+        if length > 1 && i + length <= chars.len() {
+            let bytes: Option<Vec<u8>> = chars[i..i + length].iter().map(|&c| cp1252_byte(c)).collect();
+            if let Some(decoded) = bytes.and_then(|bytes| String::from_utf8(bytes).ok()) {
+                output.push_str(&decoded);
+                repaired = true;
+                i += length;
+                continue;
+            }
+        }
+        output.push(chars[i]);
+        i += 1;
+    }
+    repaired
+}
+
+#[derive(Deserialize)]
+pub struct MojibakeKwargs {
+    #[serde(default)]
+    aggressive: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn fix_mojibake(inputs: &[Series], kwargs: MojibakeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        if value.is_ascii() {
+            output.push_str(value);
+        } else if !kwargs.aggressive {
+            match repair_mojibake(value) {
+                Some(repaired) => output.push_str(&repaired),
+                None => output.push_str(value),
+            }
+        } else {
+            // Keep repairing until no sequence is left, undoing repeated misreads
+            let mut current = value.to_string();
+            let mut repaired = String::with_capacity(value.len());
+            while repair_mojibake_sequences(&current, &mut repaired) {
+                std::mem::swap(&mut current, &mut repaired);
+                repaired.clear();
+            }
+            output.push_str(&current);
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct SplitFixedKwargs {
+    separator: String,
+    n: usize,
+    #[serde(default)]
+    fill: Option<String>,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn split_fixed_field_names(n: usize) -> impl Iterator<Item = PlSmallStr> {
+    (0..n).map(|i| format!("field_{i}").into())
+}
+
+fn split_fixed_output(input_fields: &[Field], kwargs: SplitFixedKwargs) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let fields = split_fixed_field_names(kwargs.n).map(|name| Field::new(name, DataType::String)).collect();
+    Ok(Field::new(field.name().clone(), DataType::Struct(fields)))
+}
+
+#[polars_expr(output_type_func_with_kwargs=split_fixed_output)]
+fn split_fixed(inputs: &[Series], kwargs: SplitFixedKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(kwargs.n > 0, ComputeError: "n must be greater than 0");
+    polars_ensure!(!kwargs.separator.is_empty(), ComputeError: "separator must not be empty");
+    par_map_slices(ca, |ca| {
+        let mut parts: Vec<Vec<Option<&str>>> = vec![Vec::with_capacity(ca.len()); kwargs.n];
+        for value in ca.iter() {
+            let Some(value) = value else {
+                parts.iter_mut().for_each(|field| field.push(None));
+                continue;
+            };
+            // The last field keeps whatever is left over, separators included
+            let mut split = value.splitn(kwargs.n, kwargs.separator.as_str());
+            for field in parts.iter_mut() {
+                field.push(split.next().or(kwargs.fill.as_deref()));
+            }
+        }
+        let fields: Vec<Series> = split_fixed_field_names(kwargs.n)
+            .zip(&parts)
+            .map(|(name, field)| Series::new(name, field))
+            .collect();
+        let out = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
+        Ok(out.with_outer_validity(ca.rechunk_validity()).into_series())
+    })
+}
+
+#[derive(Deserialize)]
+pub struct ChunkKwargs {
+    size: usize,
+    #[serde(default)]
+    grapheme: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+fn chunk_parts(value: &str, size: usize, grapheme: bool) -> Vec<&str> {
+    let starts: Vec<usize> = if grapheme {
+        value.grapheme_indices(true).map(|(i, _)| i).step_by(size).collect()
+    } else {
+        value.char_indices().map(|(i, _)| i).step_by(size).collect()
+    };
+    // Each chunk runs up to where the next one starts, so the last may be shorter
+    let ends = starts.iter().skip(1).copied().chain(std::iter::once(value.len()));
+    starts.iter().zip(ends).map(|(&start, end)| &value[start..end]).collect()
+}
+
+#[polars_expr(output_type_func=list_string_output)]
+fn chunk(inputs: &[Series], kwargs: ChunkKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(kwargs.size > 0, ComputeError: "size must be greater than 0");
+    par_map_slices(ca, |ca| {
+        let mut builder = ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.get_values_size());
+        for value in ca.iter() {
+            match value {
+                Some(value) => builder.append_values_iter(chunk_parts(value, kwargs.size, kwargs.grapheme).into_iter()),
+                None => builder.append_null(),
+            }
+        }
+        Ok(builder.finish().into_series())
+    })
+}
+
+fn default_group_size() -> usize {
+    3
+}
+
+fn default_group_sep() -> String {
+    ",".to_string()
+}
+
+fn default_decimal_sep() -> String {
+    ".".to_string()
+}
+
+#[derive(Deserialize)]
+pub struct GroupKwargs {
+    #[serde(default = "default_group_size")]
+    group_size: usize,
+    #[serde(default = "default_group_sep")]
+    group_sep: String,
+    #[serde(default = "default_decimal_sep")]
+    decimal_sep: String,
+    #[serde(default)]
+    strict: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+// Only an optional sign, integer digits and an optional decimal portion count as numeric
+fn push_grouped_digits(value: &str, kwargs: &GroupKwargs, output: &mut String) -> bool {
+    let (sign, unsigned) = match value.strip_prefix(['+', '-']) {
+        Some(unsigned) => (&value[..1], unsigned),
+        None => ("", value),
+    };
+    let (integer, fraction) = match unsigned.split_once(kwargs.decimal_sep.as_str()) {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (unsigned, None),
+    };
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if integer.is_empty() || !is_digits(integer) || !fraction.is_none_or(is_digits) {
+        return false;
+    }
+    output.push_str(sign);
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i).is_multiple_of(kwargs.group_size) {
+            output.push_str(&kwargs.group_sep);
+        }
+        output.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        output.push_str(&kwargs.decimal_sep);
+        output.push_str(fraction);
+    }
+    true
+}
+
+#[polars_expr(output_type=String)]
+fn group_digits(inputs: &[Series], kwargs: GroupKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(kwargs.group_size > 0, ComputeError: "group_size must be greater than 0");
+    polars_ensure!(!kwargs.decimal_sep.is_empty(), ComputeError: "decimal_sep must not be empty");
+    let out: StringChunked = par_try_apply_into_string(ca, |value: &str, output: &mut String| -> PolarsResult<()> {
+        if !push_grouped_digits(value, &kwargs, output) {
+            // Leave non-numeric values unchanged unless in strict mode
+            polars_ensure!(!kwargs.strict, ComputeError: "'{}' is not a numeric value", value);
+            output.push_str(value);
+        }
+        Ok(())
+    })?;
+    Ok(out.into_series())
+}
+
+fn default_token_separator() -> String {
+    ",".to_string()
+}
+
+fn default_keep() -> String {
+    "first".to_string()
+}
+
+#[derive(Deserialize)]
+pub struct DedupeKwargs {
+    #[serde(default = "default_token_separator")]
+    separator: String,
+    #[serde(default = "default_keep")]
+    keep: String,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn dedupe_tokens(inputs: &[Series], kwargs: DedupeKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(!kwargs.separator.is_empty(), ComputeError: "separator must not be empty");
+    polars_ensure!(
+        matches!(kwargs.keep.as_str(), "first" | "last"),
+        ComputeError: "keep must be 'first' or 'last', got '{}'", kwargs.keep
+    );
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        // Tokens are compared and rejoined without their surrounding whitespace
+        let mut seen = PlHashSet::new();
+        let tokens: Vec<&str> = if kwargs.keep == "first" {
+            value.split(kwargs.separator.as_str()).map(str::trim).filter(|token| seen.insert(*token)).collect()
+        } else {
+            let mut tokens: Vec<&str> =
+                value.rsplit(kwargs.separator.as_str()).map(str::trim).filter(|token| seen.insert(*token)).collect();
+            tokens.reverse();
+            tokens
+        };
+        output.push_str(&tokens.join(&kwargs.separator));
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct SortTokensKwargs {
+    #[serde(default = "default_token_separator")]
+    separator: String,
+    #[serde(default)]
+    descending: bool,
+    #[serde(default)]
+    case_insensitive: bool,
+    #[serde(default)]
+    numeric: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn sort_tokens(inputs: &[Series], kwargs: SortTokensKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(!kwargs.separator.is_empty(), ComputeError: "separator must not be empty");
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        // Each token is paired with its sort keys so they are computed once per row
+        let mut tokens: Vec<(Option<f64>, String, &str)> = value
+            .split(kwargs.separator.as_str())
+            .map(str::trim)
+            .map(|token| {
+                let number = if kwargs.numeric { token.parse::<f64>().ok() } else { None };
+                let folded = if kwargs.case_insensitive { token.to_lowercase() } else { String::new() };
+                (number, folded, token)
+            })
+            .collect();
+        // Numbers come before other tokens, and ties fall back to the original text
+        tokens.sort_by(|(a_number, a_folded, a), (b_number, b_folded, b)| {
+            let ordering = match (a_number, b_number) {
+                (Some(a_number), Some(b_number)) => a_number.total_cmp(b_number),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => a_folded.cmp(b_folded),
+            };
+            let ordering = ordering.then_with(|| a.cmp(b));
+            if kwargs.descending { ordering.reverse() } else { ordering }
+        });
+        for (i, (_, _, token)) in tokens.iter().enumerate() {
+            if i > 0 {
+                output.push_str(&kwargs.separator);
+            }
+            output.push_str(token);
+        }
+    })?;
+    Ok(out.into_series())
+}
+
+#[derive(Deserialize)]
+pub struct AnagramKwargs {
+    #[serde(default = "default_true")]
+    ignore_case: bool,
+    #[serde(default = "default_true")]
+    ignore_non_letters: bool,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+#[polars_expr(output_type=String)]
+fn anagram_key(inputs: &[Series], kwargs: AnagramKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    let out: StringChunked = par_apply_into_string(ca, |value: &str, output: &mut String| {
+        let mut chars: Vec<char> = if kwargs.ignore_case {
+            value.chars().flat_map(char::to_lowercase).collect()
+        } else {
+            value.chars().collect()
+        };
+        if kwargs.ignore_non_letters {
+            chars.retain(|c| c.is_alphabetic());
+        }
+        chars.sort_unstable();
+        output.extend(chars);
+    })?;
+    Ok(out.into_series())
+}
+
+const GUESS_ENCODING_FIELDS: [&str; 2] = ["encoding", "confidence"];
+
+fn guess_encoding_output(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let fields = vec![
+        Field::new(GUESS_ENCODING_FIELDS[0].into(), DataType::String),
+        Field::new(GUESS_ENCODING_FIELDS[1].into(), DataType::Float64),
+    ];
+    Ok(Field::new(field.name().clone(), DataType::Struct(fields)))
+}
+
+// Bytes Windows-1252 leaves undefined, which only make sense as Latin-1 control codes
+fn is_cp1252_undefined(byte: u8) -> bool {
+    matches!(byte, 0x81 | 0x8D | 0x8F | 0x90 | 0x9D)
+}
+
+// Empty input carries no evidence either way, so it gets no guess
+fn guess_encoding_value(bytes: &[u8]) -> Option<(&'static str, f64)> {
+    if bytes.is_empty() {
+        return None;
+    }
+    let boms: [(&[u8], &str); 3] = [(&[0xEF, 0xBB, 0xBF], "utf-8"), (&[0xFF, 0xFE], "utf-16le"), (&[0xFE, 0xFF], "utf-16be")];
+    if let Some((_, encoding)) = boms.iter().find(|(bom, _)| bytes.starts_with(bom)) {
+        return Some((encoding, 1.0));
+    }
+    // Mostly Latin text in UTF-16 has a zero byte in every other position
+    if bytes.len().is_multiple_of(2) {
+        let pairs = bytes.len() / 2;
+        let zeros_at = |offset: usize| bytes.iter().skip(offset).step_by(2).filter(|&&b| b == 0).count();
+        for (encoding, zeros) in [("utf-16le", zeros_at(1)), ("utf-16be", zeros_at(0))] {
+            if zeros * 2 >= pairs {
+                return Some((encoding, zeros as f64 / pairs as f64));
+            }
+        }
+    }
+    if bytes.is_ascii() {
+        return Some(("ascii", 1.0));
+    }
+    // Every valid multibyte sequence makes an accidental UTF-8 match less likely
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        let multibyte = text.chars().filter(|c| !c.is_ascii()).count();
+        let confidence = if multibyte < 6 { 1.0 - 0.99 * 0.5_f64.powi(multibyte as i32) } else { 0.99 };
+        return Some(("utf-8", confidence));
+    }
+    // Any byte decodes in a single-byte encoding, so the confidence is capped well below certain
+    let has_cp1252_specials = bytes.iter().any(|b| (0x80..=0x9F).contains(b));
+    let encoding = if has_cp1252_specials && !bytes.iter().copied().any(is_cp1252_undefined) {
+        "windows-1252"
+    } else {
+        "iso-8859-1"
+    };
+    let printable = bytes
+        .iter()
+        .filter(|&&b| {
+            b.is_ascii_graphic()
+                || b.is_ascii_whitespace()
+                || b >= 0xA0
+                || (encoding == "windows-1252" && (0x80..=0x9F).contains(&b))
+        })
+        .count();
+    Some((encoding, 0.8 * printable as f64 / bytes.len() as f64))
+}
+
+#[polars_expr(output_type_func=guess_encoding_output)]
+fn guess_encoding(inputs: &[Series], kwargs: NullModeKwargs) -> PolarsResult<Series> {
+    let ca: &BinaryChunked = inputs[0].binary()?;
+    ensure_no_nulls(ca.null_count(), kwargs.null_mode)?;
+    par_map_slices(ca, |ca| {
+        let guesses: Vec<Option<(&str, f64)>> = ca.iter().map(|value| value.and_then(guess_encoding_value)).collect();
+        let encodings: StringChunked = guesses.iter().map(|guess| guess.map(|(encoding, _)| encoding)).collect();
+        let confidences: Float64Chunked = guesses.iter().map(|guess| guess.map(|(_, confidence)| confidence)).collect();
+        let fields = [
+            encodings.with_name(GUESS_ENCODING_FIELDS[0].into()).into_series(),
+            confidences.with_name(GUESS_ENCODING_FIELDS[1].into()).into_series(),
+        ];
+        // Null and empty rows are null structs, not structs of nulls
+        let validity: Bitmap = guesses.iter().map(Option::is_some).collect();
+        let out = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
+        Ok(out.with_outer_validity(Some(validity)).into_series())
+    })
+}
+
+fn default_decode_errors() -> String {
+    "strict".to_string()
+}
+
+#[derive(Deserialize)]
+pub struct DecodeKwargs {
+    encoding: String,
+    #[serde(default = "default_decode_errors")]
+    errors: String,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+// WHATWG folds "ascii" and the Latin-1 labels into windows-1252, so those two are decoded byte by byte here
+#[derive(Clone, Copy)]
+enum SourceEncoding {
+    Ascii,
+    Latin1,
+    Whatwg(&'static Encoding),
+}
+
+impl SourceEncoding {
+    fn from_label(label: &str) -> PolarsResult<Self> {
+        let source = match label.trim().to_ascii_lowercase().as_str() {
+            "ascii" | "us-ascii" => SourceEncoding::Ascii,
+            "iso-8859-1" | "iso8859-1" | "iso_8859-1" | "latin1" | "latin-1" | "l1" => SourceEncoding::Latin1,
+            _ => match Encoding::for_label(label.as_bytes()) {
+                // Labels such as "iso-2022-kr" resolve to an encoding that turns any input into one U+FFFD
+                Some(encoding) if encoding != REPLACEMENT => SourceEncoding::Whatwg(encoding),
+                _ => polars_bail!(ComputeError: "unsupported encoding '{}'", label),
+            },
+        };
+        Ok(source)
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            SourceEncoding::Ascii => "ascii",
+            SourceEncoding::Latin1 => "iso-8859-1",
+            SourceEncoding::Whatwg(encoding) => encoding.name(),
+        }
+    }
+}
+
+// Gives false in strict mode, where the whole value fails instead
+fn push_undecodable(output: &mut String, errors: &str) -> bool {
+    match errors {
+        "replace" => {
+            output.push(char::REPLACEMENT_CHARACTER);
+            true
+        },
+        "ignore" => true,
+        _ => false,
+    }
+}
+
+// Gives nothing back in strict mode once anything fails to decode
+fn decode_bytes_value(bytes: &[u8], source: SourceEncoding, errors: &str) -> Option<String> {
+    let encoding = match source {
+        SourceEncoding::Whatwg(encoding) => encoding,
+        SourceEncoding::Ascii | SourceEncoding::Latin1 => {
+            let mut output = String::with_capacity(bytes.len());
+            for &byte in bytes {
+                if byte.is_ascii() || matches!(source, SourceEncoding::Latin1) {
+                    output.push(byte as char);
+                } else if !push_undecodable(&mut output, errors) {
+                    return None;
+                }
+            }
+            return Some(output);
+        },
+    };
+    let mut decoder = encoding.new_decoder_without_bom_handling();
+    let mut output = String::new();
+    let mut rest = bytes;
+    loop {
+        if let Some(needed) = decoder.max_utf8_buffer_length_without_replacement(rest.len()) {
+            output.reserve(needed);
+        }
+        let (result, read) = decoder.decode_to_string_without_replacement(rest, &mut output, true);
+        rest = &rest[read..];
+        match result {
+            DecoderResult::InputEmpty => return Some(output),
+            DecoderResult::OutputFull => {},
+            DecoderResult::Malformed(..) => {
+                if !push_undecodable(&mut output, errors) {
+                    return None;
+                }
+            },
+        }
+    }
+}
+
+#[polars_expr(output_type=String)]
+fn decode_bytes(inputs: &[Series], kwargs: DecodeKwargs) -> PolarsResult<Series> {
+    let ca: &BinaryChunked = inputs[0].binary()?;
+    ensure_no_nulls(ca.null_count(), kwargs.null_mode)?;
+    // Any other WHATWG label is accepted too, e.g. "shift_jis" or "cp1252"
+    let source = SourceEncoding::from_label(&kwargs.encoding)?;
+    polars_ensure!(
+        matches!(kwargs.errors.as_str(), "strict" | "replace" | "ignore"),
+        ComputeError: "errors must be 'strict', 'replace' or 'ignore', got '{}'", kwargs.errors
+    );
+    let out = par_map_ranges(ca.len(), |offset, len| {
+        let out: StringChunked = ca
+            .slice(offset, len)
+            .iter()
+            .enumerate()
+            .map(|(i, value)| -> PolarsResult<Option<String>> {
+                let Some(value) = value else {
+                    return Ok(None);
+                };
+                match decode_bytes_value(value, source, &kwargs.errors) {
+                    Some(decoded) => Ok(Some(decoded)),
+                    None => {
+                        let preview = &value[..value.len().min(16)];
+                        let ellipsis = if preview.len() < value.len() { "..." } else { "" };
+                        polars_bail!(
+                            ComputeError: "value b'{}{}' at row {} is not valid {}",
+                            preview.escape_ascii(), ellipsis, offset as usize + i, source.name()
+                        )
+                    },
+                }
+            })
+            .collect::<PolarsResult<StringChunked>>()?;
+        Ok(out.into_series())
+    })?;
+    let out = fill_null_output(out.str()?.clone(), kwargs.null_mode);
+    Ok(out.with_name(ca.name().clone()).into_series())
+}
+
+fn default_best_match_metric() -> String {
+    "jaro_winkler".to_string()
+}
+
+fn default_best_match_threshold() -> f64 {
+    0.8
+}
+
+#[derive(Deserialize)]
+pub struct BestMatchKwargs {
+    candidates: Vec<String>,
+    #[serde(default = "default_best_match_metric")]
+    metric: String,
+    #[serde(default = "default_best_match_threshold")]
+    threshold: f64,
+    #[serde(default)]
+    null_mode: NullMode,
+}
+
+const BEST_MATCH_FIELDS: [&str; 2] = ["match", "score"];
+
+fn best_match_output(input_fields: &[Field]) -> PolarsResult<Field> {
+    let field = &input_fields[0];
+    let fields = vec![
+        Field::new(BEST_MATCH_FIELDS[0].into(), DataType::String),
+        Field::new(BEST_MATCH_FIELDS[1].into(), DataType::Float64),
+    ];
+    Ok(Field::new(field.name().clone(), DataType::Struct(fields)))
+}
+
+// Every metric scores from 0 for nothing in common to 1 for identical strings
+fn similarity_score(a: &[char], b: &[char], metric: &str) -> f64 {
+    match metric {
+        "levenshtein" => {
+            let longest = a.len().max(b.len());
+            if longest == 0 {
+                return 1.0;
+            }
+            1.0 - levenshtein_distance(a, b) as f64 / longest as f64
+        },
+        "ratio" => matching_ratio(a, b),
+        _ => jaro_winkler_similarity(a, b, default_jaro_winkler_prefix_weight()),
+    }
+}
+
+#[polars_expr(output_type_func=best_match_output)]
+fn best_match(inputs: &[Series], kwargs: BestMatchKwargs) -> PolarsResult<Series> {
+    let ca: &StringChunked = &apply_null_mode(inputs[0].str()?, kwargs.null_mode)?;
+    polars_ensure!(!kwargs.candidates.is_empty(), ComputeError: "candidates must not be empty");
+    polars_ensure!(
+        matches!(kwargs.metric.as_str(), "jaro_winkler" | "levenshtein" | "ratio"),
+        ComputeError: "metric must be 'jaro_winkler', 'levenshtein' or 'ratio', got '{}'", kwargs.metric
+    );
+    polars_ensure!(
+        (0.0..=1.0).contains(&kwargs.threshold),
+        ComputeError: "threshold must be between 0 and 1, got {}", kwargs.threshold
+    );
+    let candidates: Vec<Vec<char>> = kwargs.candidates.iter().map(|candidate| candidate.chars().collect()).collect();
+    par_map_slices(ca, |ca| {
+        // Ties go to the candidate listed first
+        let matches: Vec<Option<(&str, f64)>> = ca
+            .iter()
+            .map(|value| {
+                let value: Vec<char> = value?.chars().collect();
+                let mut best: Option<(&str, f64)> = None;
+                for (candidate, chars) in kwargs.candidates.iter().zip(&candidates) {
+                    let score = similarity_score(&value, chars, &kwargs.metric);
+                    if score >= kwargs.threshold && best.is_none_or(|(_, best_score)| score > best_score) {
+                        best = Some((candidate, score));
+                    }
+                }
+                best
+            })
+            .collect();
+        let matched: StringChunked = matches.iter().map(|best| best.map(|(candidate, _)| candidate)).collect();
+        let scores: Float64Chunked = matches.iter().map(|best| best.map(|(_, score)| score)).collect();
+        let fields = [
+            matched.with_name(BEST_MATCH_FIELDS[0].into()).into_series(),
+            scores.with_name(BEST_MATCH_FIELDS[1].into()).into_series(),
+        ];
+        // Rows without a candidate above the threshold are null structs
+        let validity: Bitmap = matches.iter().map(Option::is_some).collect();
+        let out = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
+        Ok(out.with_outer_validity(Some(validity)).into_series())
+    })
+}
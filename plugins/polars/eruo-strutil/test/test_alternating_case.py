@@ -0,0 +1,41 @@
+from eruo_strutil import to_alternating_case
+import polars
+
+
+def test_to_alternating_case():
+    df = polars.DataFrame({
+        'input': ['hello world', 'ABC', '', None],
+        'expected': ['hElLo wOrLd', 'aBc', '', None],
+    })
+    df = df.with_columns(output=to_alternating_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_to_alternating_case_start_upper():
+    df = polars.DataFrame({
+        'input': ['hello world'],
+        'expected': ['HeLlO WoRlD'],
+    })
+    df = df.with_columns(output=to_alternating_case('input', start_upper=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_to_alternating_case_skip_nonalpha():
+    df = polars.DataFrame({
+        'input': ['hello world', 'a1b2c3'],
+        'expected': ['hElLo WoRlD', 'a1B2c3'],
+    })
+    df = df.with_columns(output=to_alternating_case('input', skip_nonalpha=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_to_alternating_case_is_deterministic():
+    df = polars.DataFrame({
+        'input': ['the same input'] * 3,
+    })
+    df = df.with_columns(output=to_alternating_case('input'))
+
+    assert df['output'].n_unique() == 1
@@ -0,0 +1,40 @@
+from eruo_strutil import anagram_key
+import polars
+
+
+def test_anagram_key():
+    df = polars.DataFrame({
+        'input': ['listen', 'silent', 'hello', None],
+        'expected': ['eilnst', 'eilnst', 'ehllo', None],
+    })
+    df = df.with_columns(output=anagram_key('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+    assert df['output'][0] == df['output'][1]
+    assert df['output'][0] != df['output'][2]
+
+
+def test_anagram_key_ignores_case_and_non_letters():
+    df = polars.DataFrame({
+        'input': ['Dormitory', 'dirty room!'],
+    })
+    df = df.with_columns(
+        default=anagram_key('input'),
+        strict=anagram_key('input', ignore_case=False, ignore_non_letters=False),
+    )
+
+    assert df['default'].to_list() == ['dimoorrty', 'dimoorrty']
+    assert df['strict'].to_list() == ['Dimoorrty', ' !dimoorrty']
+
+
+def test_anagram_key_group_by():
+    df = polars.DataFrame({
+        'input': ['listen', 'silent', 'enlist', 'google', 'banana'],
+    })
+    groups = (
+        df.group_by(anagram_key('input').alias('key'))
+        .agg(polars.col('input').sort())
+        .sort('key')
+    )
+
+    assert groups['input'].to_list() == [['banana'], ['google'], ['enlist', 'listen', 'silent']]
@@ -0,0 +1,61 @@
+from eruo_strutil import base32_decode, base32_encode
+import polars
+import pytest
+
+
+def test_base32_encode():
+    df = polars.DataFrame({
+        'input': ['foobar', 'héllo', 'f', '', None],
+        'expected': ['MZXW6YTBOI======', 'NDB2S3DMN4======', 'MY======', '', None],
+    })
+    df = df.with_columns(output=base32_encode('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_base32_encode_options():
+    df = polars.DataFrame({
+        'input': ['foobar'],
+    })
+    df = df.with_columns(
+        unpadded=base32_encode('input', padding=False),
+        lowercase=base32_encode('input', lowercase=True),
+    )
+
+    assert df['unpadded'].to_list() == ['MZXW6YTBOI']
+    assert df['lowercase'].to_list() == ['mzxw6ytboi======']
+
+
+def test_base32_round_trip():
+    df = polars.DataFrame({
+        'input': ['hello world', 'héllo wörld', 'fooba', None],
+    })
+    df = df.with_columns(
+        padded=base32_decode(base32_encode('input')),
+        unpadded=base32_decode(base32_encode('input', padding=False, lowercase=True)),
+    )
+
+    assert df['padded'].to_list() == df['input'].to_list()
+    assert df['unpadded'].to_list() == df['input'].to_list()
+
+
+def test_base32_decode_case_insensitive():
+    df = polars.DataFrame({
+        'input': ['MZXW6YTBOI======', 'mzxw6ytboi', 'MzXw6YtBoI'],
+    })
+    df = df.with_columns(output=base32_decode('input'))
+
+    assert df['output'].to_list() == ['foobar', 'foobar', 'foobar']
+
+
+def test_base32_decode_malformed():
+    df = polars.DataFrame({
+        'input': ['MZXW6===', 'MZXW1===', 'MZXW6YTBO'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError, match="invalid character '1'"):
+        df.with_columns(output=base32_decode('input'))
+
+    df = df.with_columns(output=base32_decode('input', strict=False))
+
+    assert df['output'].to_list() == ['foo', None, None]
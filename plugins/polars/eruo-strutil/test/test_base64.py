@@ -0,0 +1,52 @@
+from eruo_strutil import base64_decode, base64_encode
+import polars
+import pytest
+
+
+def test_base64_encode():
+    df = polars.DataFrame({
+        'input': ['hello world', 'héllo', '', None],
+        'expected': ['aGVsbG8gd29ybGQ=', 'aMOpbGxv', '', None],
+    })
+    df = df.with_columns(output=base64_encode('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_base64_round_trip():
+    df = polars.DataFrame({
+        'input': ['hello world', 'héllo wörld', '??>>', None],
+    })
+    df = df.with_columns(
+        standard=base64_decode(base64_encode('input')),
+        url_safe=base64_decode(base64_encode('input', url_safe=True), url_safe=True),
+    )
+
+    assert df['standard'].to_list() == df['input'].to_list()
+    assert df['url_safe'].to_list() == df['input'].to_list()
+
+
+def test_base64_encode_url_safe():
+    df = polars.DataFrame({
+        'input': ['??>>'],
+    })
+    df = df.with_columns(
+        standard=base64_encode('input'),
+        url_safe=base64_encode('input', url_safe=True),
+    )
+
+    assert df['standard'].to_list() == ['Pz8+Pg==']
+    assert df['url_safe'].to_list() == ['Pz8-Pg==']
+
+
+def test_base64_decode_malformed():
+    df = polars.DataFrame({
+        'input': ['aGVsbG8=', 'not base64!', '/w=='],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=base64_decode('input'))
+
+    df = df.with_columns(output=base64_decode('input', strict=False))
+
+    assert df['output'].to_list() == ['hello', None, None]
@@ -0,0 +1,52 @@
+from eruo_strutil import best_match
+import polars
+import pytest
+
+
+CANDIDATES = ['Apple Inc', 'Microsoft Corp', 'Alphabet Inc']
+
+
+def test_best_match():
+    df = polars.DataFrame({
+        'input': ['Microsft Corp', 'Banana Republic', 'Apple Inc', None],
+    })
+    df = df.with_columns(output=best_match('input', CANDIDATES))
+    matches = df['output'].to_list()
+
+    assert matches[0]['match'] == 'Microsoft Corp'
+    assert 0.8 < matches[0]['score'] < 1.0
+    assert matches[1] is None
+    assert matches[2] == {'match': 'Apple Inc', 'score': 1.0}
+    assert matches[3] is None
+
+
+def test_best_match_metrics():
+    df = polars.DataFrame({
+        'input': ['Microsft Corp'],
+    })
+    df = df.with_columns(
+        levenshtein=best_match('input', CANDIDATES, metric='levenshtein'),
+        ratio=best_match('input', CANDIDATES, metric='ratio'),
+    )
+
+    assert df['levenshtein'].struct.field('match').to_list() == ['Microsoft Corp']
+    assert df['levenshtein'].struct.field('score').to_list() == pytest.approx([13 / 14])
+    assert df['ratio'].struct.field('match').to_list() == ['Microsoft Corp']
+
+
+def test_best_match_threshold():
+    df = polars.DataFrame({
+        'input': ['Microsft Corp'],
+    })
+    df = df.with_columns(output=best_match('input', CANDIDATES, metric='levenshtein', threshold=0.95))
+
+    assert df['output'].to_list() == [None]
+
+
+def test_best_match_invalid_metric():
+    df = polars.DataFrame({
+        'input': ['Apple'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=best_match('input', CANDIDATES, metric='cosine'))
@@ -0,0 +1,48 @@
+from eruo_strutil import caseless_equals
+import polars
+
+
+def test_caseless_equals():
+    df = polars.DataFrame({
+        'left': ['Hello', 'ﬁle', 'apple', None],
+        'right': ['hELLO', 'FILE', 'apples', 'x'],
+        'expected': [True, True, False, None],
+    })
+    df = df.with_columns(output=caseless_equals('left', 'right'))
+
+    assert df['output'].dtype == polars.Boolean
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_caseless_equals_eszett():
+    df = polars.DataFrame({
+        'left': ['straße', 'STRASSE', 'Maße'],
+        'right': ['STRASSE', 'straße', 'masse'],
+        'expected': [True, True, True],
+    })
+    df = df.with_columns(output=caseless_equals('left', 'right'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_caseless_equals_turkish_i():
+    # The dotted capital İ folds to i with a combining dot, and the dotless ı only matches itself
+    df = polars.DataFrame({
+        'left': ['İstanbul', 'İ', 'ı', 'ı'],
+        'right': ['i\u0307stanbul', 'i', 'I', 'ı'],
+        'expected': [True, False, False, True],
+    })
+    df = df.with_columns(output=caseless_equals('left', 'right'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_caseless_equals_final_sigma():
+    df = polars.DataFrame({
+        'left': ['ΟΔΟΣ', 'όδος', 'ςσΣ'],
+        'right': ['οδος', 'ΌΔΟΣ', 'σςσ'],
+        'expected': [True, True, True],
+    })
+    df = df.with_columns(output=caseless_equals('left', 'right'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,51 @@
+from eruo_strutil import center
+import polars
+import pytest
+
+
+def test_center_even_padding():
+    df = polars.DataFrame({
+        'input': ['ab', 'abcd', None],
+        'expected': ['**ab**', '*abcd*', None],
+    })
+    df = df.with_columns(output=center('input', length=6, fill='*'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_center_odd_padding():
+    df = polars.DataFrame({
+        'input': ['abc', 'a'],
+        'expected': [' abc  ', '  a   '],
+    })
+    df = df.with_columns(output=center('input', length=6))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_center_accented():
+    df = polars.DataFrame({
+        'input': ['café'],
+        'expected': ['-café-'],
+    })
+    df = df.with_columns(output=center('input', length=6, fill='-'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_center_longer_than_length():
+    df = polars.DataFrame({
+        'input': ['toolong'],
+    })
+    df = df.with_columns(output=center('input', length=4, fill='-'))
+
+    assert df['output'].to_list() == df['input'].to_list()
+
+
+def test_center_empty_fill():
+    df = polars.DataFrame({
+        'input': ['a'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=center('input', length=3, fill=''))
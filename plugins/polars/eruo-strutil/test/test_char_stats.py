@@ -0,0 +1,41 @@
+from eruo_strutil import char_stats
+import polars
+
+
+def test_char_stats():
+    df = polars.DataFrame({
+        'input': ['Héllo, wörld 42!', 'Καλημέρα ٣', None],
+    })
+    df = df.with_columns(output=char_stats('input')).unnest('output')
+
+    assert df['letters'].to_list() == [10, 8, None]
+    assert df['digits'].to_list() == [2, 1, None]
+    assert df['whitespace'].to_list() == [2, 1, None]
+    assert df['punctuation'].to_list() == [2, 0, None]
+    assert df['other'].to_list() == [0, 0, None]
+
+
+def test_char_stats_null_struct():
+    df = polars.DataFrame({
+        'input': ['abc', None],
+    })
+    df = df.with_columns(output=char_stats('input'))
+
+    assert df['output'].to_list() == [
+        {'letters': 3, 'digits': 0, 'whitespace': 0, 'punctuation': 0, 'other': 0},
+        None,
+    ]
+
+
+def test_char_stats_sum_to_char_count():
+    df = polars.DataFrame({
+        'input': ['Price: 12€ + tax (~5%)\n日本語 👍🏽 e\u0301'],
+    })
+    df = df.with_columns(
+        output=char_stats('input'),
+        total=polars.col('input').str.len_chars(),
+    ).unnest('output')
+    row = df.row(0, named=True)
+
+    assert sum(row[field] for field in ['letters', 'digits', 'whitespace', 'punctuation', 'other']) == row['total']
+    assert row['other'] > 0
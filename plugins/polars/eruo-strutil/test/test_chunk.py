@@ -0,0 +1,45 @@
+from eruo_strutil import chunk
+import polars
+import pytest
+
+
+def test_chunk_exact_multiple():
+    df = polars.DataFrame({
+        'input': ['ABCDEFGHIJKL', '', None],
+        'expected': [['ABCD', 'EFGH', 'IJKL'], [], None],
+    })
+    df = df.with_columns(output=chunk('input', 4))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_chunk_remainder():
+    df = polars.DataFrame({
+        'input': ['ABCDEFGHIJ', 'héllo'],
+        'expected': [['ABC', 'DEF', 'GHI', 'J'], ['hél', 'lo']],
+    })
+    df = df.with_columns(output=chunk('input', 3))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_chunk_grapheme():
+    df = polars.DataFrame({
+        'input': ['👍🏽👨\u200d👩\u200d👧ab'],
+    })
+    df = df.with_columns(
+        chars=chunk('input', 2),
+        graphemes=chunk('input', 2, grapheme=True),
+    )
+
+    assert df['chars'].to_list() == [['👍🏽', '👨\u200d', '👩\u200d', '👧a', 'b']]
+    assert df['graphemes'].to_list() == [['👍🏽👨\u200d👩\u200d👧', 'ab']]
+
+
+def test_chunk_zero_size():
+    df = polars.DataFrame({
+        'input': ['abc'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=chunk('input', 0))
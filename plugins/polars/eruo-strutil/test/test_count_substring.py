@@ -0,0 +1,49 @@
+from eruo_strutil import count_substring
+import polars
+import pytest
+
+
+def test_count_substring():
+    df = polars.DataFrame({
+        'input': ['banana', 'no match', '', None],
+        'expected': [2, 0, 0, None],
+    })
+    df = df.with_columns(output=count_substring('input', needle='an'))
+
+    assert df['output'].dtype == polars.UInt32
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_count_substring_overlapping():
+    df = polars.DataFrame({
+        'input': ['aaaa', 'aaa'],
+    })
+    df = df.with_columns(
+        default=count_substring('input', needle='aa'),
+        overlapping=count_substring('input', needle='aa', overlapping=True),
+    )
+
+    assert df['default'].to_list() == [2, 1]
+    assert df['overlapping'].to_list() == [3, 2]
+
+
+def test_count_substring_case_insensitive():
+    df = polars.DataFrame({
+        'input': ['The cat saw THE CAT'],
+    })
+    df = df.with_columns(
+        sensitive=count_substring('input', needle='the'),
+        insensitive=count_substring('input', needle='the', case_insensitive=True),
+    )
+
+    assert df['sensitive'].to_list() == [0]
+    assert df['insensitive'].to_list() == [2]
+
+
+def test_count_substring_empty_needle():
+    df = polars.DataFrame({
+        'input': ['a'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=count_substring('input', needle=''))
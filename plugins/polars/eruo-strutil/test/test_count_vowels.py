@@ -0,0 +1,48 @@
+from eruo_strutil import count_consonants, count_vowels
+import polars
+
+
+def test_count_vowels():
+    df = polars.DataFrame({
+        'input': ['hello', 'rhythm', '', None],
+        'expected': [2, 0, 0, None],
+    })
+    df = df.with_columns(output=count_vowels('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_count_consonants():
+    df = polars.DataFrame({
+        'input': ['hello', 'rhythm', '', None],
+        'expected': [3, 6, 0, None],
+    })
+    df = df.with_columns(output=count_consonants('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_count_vowels_treat_y_as_vowel():
+    df = polars.DataFrame({
+        'input': ['rhythm'],
+    })
+    df = df.with_columns(
+        vowels=count_vowels('input', treat_y_as_vowel=True),
+        consonants=count_consonants('input', treat_y_as_vowel=True),
+    )
+
+    assert df['vowels'].to_list() == [1]
+    assert df['consonants'].to_list() == [5]
+
+
+def test_count_vowels_ascii_only():
+    df = polars.DataFrame({
+        'input': ['café'],
+    })
+    df = df.with_columns(
+        unicode=count_vowels('input'),
+        ascii=count_vowels('input', ascii_only=True),
+    )
+
+    assert df['unicode'].to_list() == [2]
+    assert df['ascii'].to_list() == [1]
@@ -0,0 +1,92 @@
+from eruo_strutil import decode_bytes
+import polars
+import pytest
+
+
+WINDOWS_1252_BYTES = 'don’t “quote” me'.encode('cp1252')
+
+
+@pytest.mark.parametrize('errors', ['strict', 'replace', 'ignore'])
+def test_decode_bytes_windows_1252(errors):
+    df = polars.DataFrame({
+        'input': [WINDOWS_1252_BYTES, b'', None],
+    })
+    df = df.with_columns(output=decode_bytes('input', 'windows-1252', errors=errors))
+
+    assert df['output'].to_list() == ['don’t “quote” me', '', None]
+
+
+def test_decode_bytes_wrong_encoding():
+    df = polars.DataFrame({
+        'input': [WINDOWS_1252_BYTES],
+    })
+    df = df.with_columns(
+        replace=decode_bytes('input', 'utf-8', errors='replace'),
+        ignore=decode_bytes('input', 'utf-8', errors='ignore'),
+    )
+
+    assert df['replace'].to_list() == ['don�t �quote� me']
+    assert df['ignore'].to_list() == ['dont quote me']
+
+    with pytest.raises(polars.exceptions.ComputeError, match='at row 0'):
+        df.with_columns(output=decode_bytes('input', 'utf-8'))
+
+
+def test_decode_bytes_encodings():
+    df = polars.DataFrame({
+        'utf8': ['héllo'.encode('utf-8')],
+        'latin1': ['héllo'.encode('latin-1')],
+        'utf16': ['héllo'.encode('utf-16-le')],
+    })
+    df = df.select(
+        decode_bytes('utf8', 'utf-8'),
+        decode_bytes('latin1', 'iso-8859-1'),
+        decode_bytes('utf16', 'utf-16le'),
+    )
+
+    assert df.row(0) == ('héllo', 'héllo', 'héllo')
+
+
+def test_decode_bytes_single_byte_labels():
+    df = polars.DataFrame({
+        'input': [b'caf\xe9 \x93ok\x94'],
+    })
+    df = df.with_columns(
+        latin1=decode_bytes('input', 'latin1'),
+        cp1252=decode_bytes('input', 'cp1252'),
+        ascii=decode_bytes('input', 'ascii', errors='replace'),
+    )
+
+    assert df['latin1'].to_list() == ['café \x93ok\x94']
+    assert df['cp1252'].to_list() == ['café “ok”']
+    assert df['ascii'].to_list() == ['caf� �ok�']
+
+
+def test_decode_bytes_ascii_strict():
+    df = polars.DataFrame({
+        'input': [b'\xe9'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=decode_bytes('input', 'ascii'))
+
+
+def test_decode_bytes_shift_jis():
+    df = polars.DataFrame({
+        'input': ['こんにちは'.encode('shift_jis'), '東京タワー'.encode('shift_jis')],
+    })
+    df = df.with_columns(output=decode_bytes('input', 'shift_jis'))
+
+    assert df['output'].to_list() == ['こんにちは', '東京タワー']
+
+
+def test_decode_bytes_unsupported_encoding():
+    df = polars.DataFrame({
+        'input': [b'abc'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=decode_bytes('input', 'ebcdic'))
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=decode_bytes('input', 'iso-2022-kr'))
@@ -0,0 +1,50 @@
+from eruo_strutil import dedupe_tokens
+import polars
+import pytest
+
+
+def test_dedupe_tokens_keep_first():
+    df = polars.DataFrame({
+        'input': ['red, blue, red, green', 'b, a, b, a', None],
+        'expected': ['red, blue, green', 'b, a', None],
+    })
+    df = df.with_columns(output=dedupe_tokens('input', ', '))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_dedupe_tokens_keep_last():
+    df = polars.DataFrame({
+        'input': ['red, blue, red, green', 'b, a, b, a', None],
+        'expected': ['blue, red, green', 'b, a', None],
+    })
+    df = df.with_columns(output=dedupe_tokens('input', ', ', keep='last'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_dedupe_tokens_trims_whitespace():
+    df = polars.DataFrame({
+        'input': ['red, blue,red ,  green'],
+    })
+    df = df.with_columns(output=dedupe_tokens('input'))
+
+    assert df['output'].to_list() == ['red,blue,green']
+
+
+def test_dedupe_tokens_no_duplicates():
+    df = polars.DataFrame({
+        'input': ['c;a;b', 'single', ''],
+    })
+    df = df.with_columns(output=dedupe_tokens('input', ';'))
+
+    assert df['output'].to_list() == df['input'].to_list()
+
+
+def test_dedupe_tokens_invalid_keep():
+    df = polars.DataFrame({
+        'input': ['a,a'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=dedupe_tokens('input', keep='none'))
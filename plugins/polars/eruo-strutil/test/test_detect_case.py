@@ -0,0 +1,32 @@
+from eruo_strutil import detect_case
+import polars
+
+
+def test_detect_case():
+    df = polars.DataFrame({
+        'input': ['userName', 'UserName', 'XMLHttpRequest', 'user_name', 'user-name', 'USER_NAME', 'Hello big world.', None],
+        'expected': ['camel', 'pascal', 'pascal', 'snake', 'kebab', 'constant', 'sentence', None],
+    })
+    df = df.with_columns(output=detect_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_detect_case_single_word():
+    df = polars.DataFrame({
+        'input': ['hello', 'Hello', 'HELLO'],
+        'expected': ['snake', 'pascal', 'constant'],
+    })
+    df = df.with_columns(output=detect_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_detect_case_unknown():
+    df = polars.DataFrame({
+        'input': ['hello World', 'Hello_World', 'user.name', '123', ''],
+        'expected': ['unknown', 'unknown', 'unknown', 'unknown', 'unknown'],
+    })
+    df = df.with_columns(output=detect_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
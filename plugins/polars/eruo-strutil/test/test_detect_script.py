@@ -0,0 +1,35 @@
+from eruo_strutil import detect_script
+import polars
+
+
+def test_detect_script():
+    df = polars.DataFrame({
+        'input': ['Hello, world!', '你好世界', 'Привет, мир', 'مرحبا', '123 !?', None],
+        'expected': ['Latin', 'Han', 'Cyrillic', 'Arabic', 'unknown', None],
+    })
+    df = df.with_columns(output=detect_script('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_detect_script_mixed():
+    df = polars.DataFrame({
+        'input': ['ab你好', 'hello 世界'],
+        'expected': ['mixed', 'Latin'],
+    })
+    df = df.with_columns(output=detect_script('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_detect_script_threshold():
+    df = polars.DataFrame({
+        'input': ['abc你好世界'],
+    })
+    df = df.with_columns(
+        strict=detect_script('input'),
+        lenient=detect_script('input', threshold=0.5),
+    )
+
+    assert df['strict'].to_list() == ['mixed']
+    assert df['lenient'].to_list() == ['Han']
@@ -0,0 +1,40 @@
+from eruo_strutil import extract_emoji, remove_emoji
+import polars
+
+FAMILY = '\U0001f468\u200d\U0001f469\u200d\U0001f467'
+THUMBS_UP_MEDIUM = '\U0001f44d\U0001f3fd'
+
+
+def test_remove_emoji():
+    df = polars.DataFrame({
+        'input': [f'family {FAMILY} time', f'nice {THUMBS_UP_MEDIUM}!', 'coffee ☕', None],
+        'expected': ['family  time', 'nice !', 'coffee ', None],
+    })
+    df = df.with_columns(output=remove_emoji('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_extract_emoji():
+    df = polars.DataFrame({
+        'input': [f'family {FAMILY} and {THUMBS_UP_MEDIUM}\U0001f602', None],
+    })
+    df = df.with_columns(output=extract_emoji('input'))
+
+    assert df['output'].to_list() == [
+        [FAMILY, THUMBS_UP_MEDIUM, '\U0001f602'],
+        None,
+    ]
+
+
+def test_emoji_plain_text():
+    df = polars.DataFrame({
+        'input': ['plain text', 'café © 2024'],
+    })
+    df = df.with_columns(
+        removed=remove_emoji('input'),
+        extracted=extract_emoji('input'),
+    )
+
+    assert df['removed'].to_list() == df['input'].to_list()
+    assert df['extracted'].to_list() == [[], []]
@@ -0,0 +1,42 @@
+from eruo_strutil import expand_contractions
+import polars
+
+
+def test_expand_contractions():
+    df = polars.DataFrame({
+        'input': ["don't", "I'm sure", "won't", "Can't stop", "DON'T", "the dogs' toys", None],
+        'expected': ['do not', 'I am sure', 'will not', 'Cannot stop', 'DO NOT', "the dogs' toys", None],
+    })
+    df = df.with_columns(output=expand_contractions('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_expand_contractions_ambiguous():
+    df = polars.DataFrame({
+        'input': ["it's raining", "It's late"],
+        'expected': ['it is raining', 'It is late'],
+    })
+    df = df.with_columns(output=expand_contractions('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_expand_contractions_curly_apostrophe():
+    df = polars.DataFrame({
+        'input': ['we’re sure they’ll come'],
+        'expected': ['we are sure they will come'],
+    })
+    df = df.with_columns(output=expand_contractions('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_expand_contractions_extra():
+    df = polars.DataFrame({
+        'input': ["the gov't said it's done"],
+        'expected': ['the government said it has done'],
+    })
+    df = df.with_columns(output=expand_contractions('input', extra=[("gov't", 'government'), ("it's", 'it has')]))
+
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,37 @@
+from eruo_strutil import extract_all_regex
+import polars
+import pytest
+
+
+def test_extract_all_regex():
+    df = polars.DataFrame({
+        'input': ['loving #rust and #polars!', 'no tags here', None],
+    })
+    df = df.with_columns(output=extract_all_regex('input', pattern=r'#\w+'))
+
+    assert df['output'].to_list() == [
+        ['#rust', '#polars'],
+        [],
+        None,
+    ]
+
+
+def test_extract_all_regex_group():
+    df = polars.DataFrame({
+        'input': ['3 apples, 12 pears', 'none'],
+    })
+    df = df.with_columns(output=extract_all_regex('input', pattern=r'(\d+) (\w+)', group=2))
+
+    assert df['output'].to_list() == [
+        ['apples', 'pears'],
+        [],
+    ]
+
+
+def test_extract_all_regex_invalid_group():
+    df = polars.DataFrame({
+        'input': ['a'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=extract_all_regex('input', pattern='a', group=1))
@@ -0,0 +1,32 @@
+from eruo_strutil import extract_emails
+import polars
+
+
+def test_extract_emails():
+    df = polars.DataFrame({
+        'input': [
+            'Contact jdoe@corp.com or ops+alerts@mail.corp.co.uk.',
+            'no mail here @ all',
+            None,
+        ],
+    })
+    df = df.with_columns(output=extract_emails('input'))
+
+    assert df['output'].to_list() == [
+        ['jdoe@corp.com', 'ops+alerts@mail.corp.co.uk'],
+        [],
+        None,
+    ]
+
+
+def test_extract_emails_dedupe():
+    df = polars.DataFrame({
+        'input': ['a@b.io, c@d.io and a@b.io again'],
+    })
+    df = df.with_columns(
+        default=extract_emails('input'),
+        deduped=extract_emails('input', dedupe=True),
+    )
+
+    assert df['default'].to_list() == [['a@b.io', 'c@d.io', 'a@b.io']]
+    assert df['deduped'].to_list() == [['a@b.io', 'c@d.io']]
@@ -0,0 +1,34 @@
+from eruo_strutil import extract_numbers
+import polars
+
+
+def test_extract_numbers():
+    df = polars.DataFrame({
+        'input': ['12 x 3.5 cm', 'no numbers', None],
+    })
+    df = df.with_columns(output=extract_numbers('input'))
+
+    assert df['output'].dtype == polars.List(polars.Float64)
+    assert df['output'].to_list() == [[12.0, 3.5], [], None]
+
+
+def test_extract_numbers_signs_and_decimals():
+    df = polars.DataFrame({
+        'input': ['from -4.25 to +7, then .5'],
+    })
+    df = df.with_columns(output=extract_numbers('input'))
+
+    assert df['output'].to_list() == [[-4.25, 7.0, 0.5]]
+
+
+def test_extract_numbers_thousands_separator():
+    df = polars.DataFrame({
+        'input': ['1,234,567.89 and -1,000'],
+    })
+    df = df.with_columns(
+        default=extract_numbers('input'),
+        thousands=extract_numbers('input', allow_thousands_separator=True),
+    )
+
+    assert df['default'].to_list() == [[1.0, 234.0, 567.89, -1.0, 0.0]]
+    assert df['thousands'].to_list() == [[1234567.89, -1000.0]]
@@ -0,0 +1,42 @@
+from eruo_strutil import extract_regex
+import polars
+import pytest
+
+
+def test_extract_regex():
+    df = polars.DataFrame({
+        'input': ['order #123 and #456', 'no order here', None],
+        'expected': ['123', None, None],
+    })
+    df = df.with_columns(output=extract_regex('input', pattern=r'#(\d+)'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_extract_regex_whole_match():
+    df = polars.DataFrame({
+        'input': ['order #123 and #456'],
+        'expected': ['#123'],
+    })
+    df = df.with_columns(output=extract_regex('input', pattern=r'#(\d+)', group=0))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_extract_regex_named_group():
+    df = polars.DataFrame({
+        'input': ['ticket AB-42 closed', 'ticket pending'],
+        'expected': ['42', None],
+    })
+    df = df.with_columns(output=extract_regex('input', pattern=r'(?P<prefix>[A-Z]+)-(?P<number>\d+)', group=2))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_extract_regex_invalid_group():
+    df = polars.DataFrame({
+        'input': ['a'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=extract_regex('input', pattern='(a)', group=2))
@@ -0,0 +1,53 @@
+from eruo_strutil import extract_urls
+import polars
+import pytest
+
+
+def test_extract_urls():
+    df = polars.DataFrame({
+        'input': [
+            'Docs at https://example.com/docs and http://x.org/b?q=1',
+            'nothing here',
+            None,
+        ],
+    })
+    df = df.with_columns(output=extract_urls('input'))
+
+    assert df['output'].to_list() == [
+        ['https://example.com/docs', 'http://x.org/b?q=1'],
+        [],
+        None,
+    ]
+
+
+def test_extract_urls_trailing_punctuation():
+    df = polars.DataFrame({
+        'input': ['See https://example.com/a. Also (http://x.org/b) and https://en.wikipedia.org/wiki/Foo_(bar)!'],
+    })
+    df = df.with_columns(output=extract_urls('input'))
+
+    assert df['output'].to_list() == [
+        ['https://example.com/a', 'http://x.org/b', 'https://en.wikipedia.org/wiki/Foo_(bar)'],
+    ]
+
+
+def test_extract_urls_schemes():
+    df = polars.DataFrame({
+        'input': ['ftp://files.example.com/x, https://a.io'],
+    })
+    df = df.with_columns(
+        default=extract_urls('input'),
+        ftp=extract_urls('input', schemes=['ftp']),
+    )
+
+    assert df['default'].to_list() == [['https://a.io']]
+    assert df['ftp'].to_list() == [['ftp://files.example.com/x']]
+
+
+def test_extract_urls_empty_schemes():
+    df = polars.DataFrame({
+        'input': ['a'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=extract_urls('input', schemes=[]))
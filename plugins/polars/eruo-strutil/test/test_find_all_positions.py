@@ -0,0 +1,44 @@
+from eruo_strutil import find_all_positions
+import polars
+import pytest
+
+
+def test_find_all_positions():
+    df = polars.DataFrame({
+        'input': ['banana', 'no match', None],
+    })
+    df = df.with_columns(output=find_all_positions('input', needle='an'))
+
+    assert df['output'].dtype == polars.List(polars.UInt32)
+    assert df['output'].to_list() == [[1, 3], [], None]
+
+
+def test_find_all_positions_multibyte():
+    df = polars.DataFrame({
+        'input': ['héé an', '日本語 an'],
+    })
+    df = df.with_columns(output=find_all_positions('input', needle='an'))
+
+    assert df['output'].to_list() == [[4], [4]]
+
+
+def test_find_all_positions_case_insensitive():
+    df = polars.DataFrame({
+        'input': ['AN an An'],
+    })
+    df = df.with_columns(
+        sensitive=find_all_positions('input', needle='an'),
+        insensitive=find_all_positions('input', needle='an', case_insensitive=True),
+    )
+
+    assert df['sensitive'].to_list() == [[3]]
+    assert df['insensitive'].to_list() == [[0, 3, 6]]
+
+
+def test_find_all_positions_empty_needle():
+    df = polars.DataFrame({
+        'input': ['a'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=find_all_positions('input', needle=''))
@@ -0,0 +1,55 @@
+from eruo_strutil import fit_width
+import polars
+import pytest
+
+
+def test_fit_width_left():
+    df = polars.DataFrame({
+        'input': ['ab', 'abcde', '', None],
+        'expected': ['ab   ', 'abcde', '     ', None],
+    })
+    df = df.with_columns(output=fit_width('input', width=5))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_fit_width_right():
+    df = polars.DataFrame({
+        'input': ['42', '12345'],
+        'expected': ['00042', '12345'],
+    })
+    df = df.with_columns(output=fit_width('input', width=5, align='right', fill='0'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_fit_width_center():
+    df = polars.DataFrame({
+        'input': ['ab', 'abc'],
+        'expected': ['-ab--', '-abc-'],
+    })
+    df = df.with_columns(output=fit_width('input', width=5, align='center', fill='-'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_fit_width_truncate():
+    df = polars.DataFrame({
+        'input': ['abcdefg', 'ab'],
+    })
+    df = df.with_columns(
+        kept=fit_width('input', width=5),
+        truncated=fit_width('input', width=5, truncate=True),
+    )
+
+    assert df['kept'].to_list() == ['abcdefg', 'ab   ']
+    assert df['truncated'].to_list() == ['abcde', 'ab   ']
+
+
+def test_fit_width_invalid_align():
+    df = polars.DataFrame({
+        'input': ['ab'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=fit_width('input', width=5, align='middle'))
@@ -0,0 +1,43 @@
+from eruo_strutil import fix_mojibake, normalize_quotes
+import polars
+
+
+def test_fix_mojibake():
+    df = polars.DataFrame({
+        'input': ['donâ€™t', 'cafÃ©', 'ZÃ¼rich â€“ â€œRÃ©sumÃ©â€\x9d', None],
+        'expected': ['don’t', 'café', 'Zürich – “Résumé”', None],
+    })
+    df = df.with_columns(output=fix_mojibake('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_fix_mojibake_with_straight_quotes():
+    df = polars.DataFrame({
+        'input': ['donâ€™t'],
+    })
+    df = df.with_columns(output=normalize_quotes(fix_mojibake('input')))
+
+    assert df['output'].to_list() == ["don't"]
+
+
+def test_fix_mojibake_clean():
+    df = polars.DataFrame({
+        'input': ['plain text', 'naïve café', 'don’t', '日本語', ''],
+    })
+    df = df.with_columns(output=fix_mojibake('input'))
+
+    assert df['output'].to_list() == df['input'].to_list()
+
+
+def test_fix_mojibake_aggressive():
+    df = polars.DataFrame({
+        'input': ['cafÃƒÂ©', 'don’t cafÃ©', '日本 cafÃ©', 'naïve café'],
+    })
+    df = df.with_columns(
+        default=fix_mojibake('input'),
+        aggressive=fix_mojibake('input', aggressive=True),
+    )
+
+    assert df['default'].to_list() == ['cafÃ©', 'don’t cafÃ©', '日本 cafÃ©', 'naïve café']
+    assert df['aggressive'].to_list() == ['café', 'don’t café', '日本 café', 'naïve café']
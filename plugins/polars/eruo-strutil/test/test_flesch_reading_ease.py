@@ -0,0 +1,35 @@
+from eruo_strutil import flesch_reading_ease
+import polars
+import pytest
+
+
+def test_flesch_reading_ease():
+    df = polars.DataFrame({
+        'input': ['The cat sat on the mat.', 'Hi. Hi there!'],
+    })
+    df = df.with_columns(output=flesch_reading_ease('input'))
+
+    # 206.835 - 1.015 * words per sentence - 84.6 * syllables per word
+    assert df['output'].to_list() == pytest.approx([116.145, 120.7125])
+
+
+def test_flesch_reading_ease_without_words():
+    df = polars.DataFrame({
+        'input': ['', '?!...', None],
+    })
+    df = df.with_columns(output=flesch_reading_ease('input'))
+
+    assert df['output'].to_list() == [None, None, None]
+
+
+def test_flesch_reading_ease_complex_text_scores_lower():
+    df = polars.DataFrame({
+        'input': [
+            'See the dog run.',
+            'Institutional considerations necessitate comprehensive organizational restructuring.',
+        ],
+    })
+    df = df.with_columns(output=flesch_reading_ease('input'))
+    easy, hard = df['output'].to_list()
+
+    assert easy > hard
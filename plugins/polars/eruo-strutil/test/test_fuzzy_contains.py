@@ -0,0 +1,58 @@
+from eruo_strutil import fuzzy_contains
+import polars
+import pytest
+
+
+def test_fuzzy_contains_exact():
+    df = polars.DataFrame({
+        'input': ['the quick brown fox', 'quick', 'slow', None],
+        'expected': [True, True, False, None],
+    })
+    df = df.with_columns(output=fuzzy_contains('input', needle='quick', max_distance=0))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_fuzzy_contains_one_typo():
+    df = polars.DataFrame({
+        'input': ['the qick brown fox', 'the quiek brown fox'],
+        'expected': [True, True],
+    })
+    df = df.with_columns(output=fuzzy_contains('input', needle='quick', max_distance=1))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_fuzzy_contains_two_typos():
+    df = polars.DataFrame({
+        'input': ['the qiuck brown fox'],
+    })
+    df = df.with_columns(
+        strict=fuzzy_contains('input', needle='quick', max_distance=1),
+        lenient=fuzzy_contains('input', needle='quick', max_distance=2),
+    )
+
+    assert df['strict'].to_list() == [False]
+    assert df['lenient'].to_list() == [True]
+
+
+def test_fuzzy_contains_case_insensitive():
+    df = polars.DataFrame({
+        'input': ['THE QUICK BROWN FOX'],
+    })
+    df = df.with_columns(
+        sensitive=fuzzy_contains('input', needle='quick', max_distance=0),
+        insensitive=fuzzy_contains('input', needle='quick', max_distance=0, case_insensitive=True),
+    )
+
+    assert df['sensitive'].to_list() == [False]
+    assert df['insensitive'].to_list() == [True]
+
+
+def test_fuzzy_contains_empty_needle():
+    df = polars.DataFrame({
+        'input': ['quick'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=fuzzy_contains('input', needle=''))
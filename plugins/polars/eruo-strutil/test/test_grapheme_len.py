@@ -0,0 +1,39 @@
+from eruo_strutil import grapheme_len
+import polars
+
+
+def test_grapheme_len():
+    df = polars.DataFrame({
+        'input': ['hello', '', None],
+        'expected': [5, 0, None],
+    })
+    df = df.with_columns(output=grapheme_len('input'))
+
+    assert df['output'].dtype == polars.UInt32
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_grapheme_len_zwj_emoji():
+    df = polars.DataFrame({
+        'input': ['\U0001f468\u200d\U0001f469\u200d\U0001f467'],
+    })
+    df = df.with_columns(
+        scalars=polars.col('input').str.len_chars(),
+        graphemes=grapheme_len('input'),
+    )
+
+    assert df['scalars'].to_list() == [5]
+    assert df['graphemes'].to_list() == [1]
+
+
+def test_grapheme_len_combining_accent():
+    df = polars.DataFrame({
+        'input': ['cafe\u0301'],
+    })
+    df = df.with_columns(
+        scalars=polars.col('input').str.len_chars(),
+        graphemes=grapheme_len('input'),
+    )
+
+    assert df['scalars'].to_list() == [5]
+    assert df['graphemes'].to_list() == [4]
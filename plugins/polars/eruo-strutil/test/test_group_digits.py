@@ -0,0 +1,38 @@
+from eruo_strutil import group_digits
+import polars
+import pytest
+
+
+def test_group_digits():
+    df = polars.DataFrame({
+        'input': ['1234567.89', '-1234567', '+1000', '999', None],
+        'expected': ['1,234,567.89', '-1,234,567', '+1,000', '999', None],
+    })
+    df = df.with_columns(output=group_digits('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_group_digits_custom_separators():
+    df = polars.DataFrame({
+        'input': ['-1234567,891', '12345678'],
+    })
+    df = df.with_columns(
+        european=group_digits('input', group_sep='.', decimal_sep=','),
+        groups_of_four=group_digits('input', group_size=4, group_sep=' ', decimal_sep=','),
+    )
+
+    assert df['european'].to_list() == ['-1.234.567,891', '12.345.678']
+    assert df['groups_of_four'].to_list() == ['-123 4567,891', '1234 5678']
+
+
+def test_group_digits_non_numeric():
+    df = polars.DataFrame({
+        'input': ['abc', '1,234', '1e5', ''],
+    })
+    df = df.with_columns(output=group_digits('input'))
+
+    assert df['output'].to_list() == df['input'].to_list()
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=group_digits('input', strict=True))
@@ -0,0 +1,41 @@
+from eruo_strutil import guess_encoding
+import polars
+
+
+def test_guess_encoding_utf8():
+    df = polars.DataFrame({
+        'input': ['naïve café résumé'.encode('utf-8')],
+    })
+    df = df.with_columns(output=guess_encoding('input')).unnest('output')
+
+    assert df['encoding'].to_list() == ['utf-8']
+    assert df['confidence'][0] > 0.9
+
+
+def test_guess_encoding_latin1():
+    df = polars.DataFrame({
+        'input': ['café crème'.encode('latin-1'), 'don’t'.encode('cp1252')],
+    })
+    df = df.with_columns(output=guess_encoding('input')).unnest('output')
+
+    assert df['encoding'].to_list() == ['iso-8859-1', 'windows-1252']
+    assert all(0 < confidence < 1 for confidence in df['confidence'])
+
+
+def test_guess_encoding_ascii_and_bom():
+    df = polars.DataFrame({
+        'input': [b'plain text', b'\xef\xbb\xbfhello', 'hi'.encode('utf-16-le')],
+    })
+    df = df.with_columns(output=guess_encoding('input')).unnest('output')
+
+    assert df['encoding'].to_list() == ['ascii', 'utf-8', 'utf-16le']
+    assert df['confidence'].to_list() == [1.0, 1.0, 1.0]
+
+
+def test_guess_encoding_empty():
+    df = polars.DataFrame({
+        'input': [b'', None],
+    }, schema={'input': polars.Binary})
+    df = df.with_columns(output=guess_encoding('input'))
+
+    assert df['output'].to_list() == [None, None]
@@ -0,0 +1,59 @@
+from eruo_strutil import hamming_distance
+import polars
+import pytest
+
+
+def test_hamming_distance():
+    df = polars.DataFrame({
+        'left': ['karolin', '1011101', 'café', None],
+        'right': ['kathrin', '1001001', 'cafe', 'x'],
+        'expected': [3, 2, 1, None],
+    })
+    df = df.with_columns(output=hamming_distance('left', 'right'))
+
+    assert df['output'].dtype == polars.UInt32
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_hamming_distance_mismatch_error():
+    df = polars.DataFrame({
+        'left': ['abcd'],
+        'right': ['ab'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=hamming_distance('left', 'right'))
+
+
+def test_hamming_distance_mismatch_null():
+    df = polars.DataFrame({
+        'left': ['abc', 'abcd'],
+        'right': ['abd', 'ab'],
+    })
+    df = df.with_columns(output=hamming_distance('left', 'right', on_length_mismatch='null'))
+
+    assert df['output'].to_list() == [1, None]
+
+
+def test_hamming_distance_mismatch_pad():
+    df = polars.DataFrame({
+        'left': ['abcd', 'ab'],
+        'right': ['ab', 'abxx'],
+    })
+    df = df.with_columns(
+        space=hamming_distance('left', 'right', on_length_mismatch='pad'),
+        x=hamming_distance('left', 'right', on_length_mismatch='pad', fill='x'),
+    )
+
+    assert df['space'].to_list() == [2, 2]
+    assert df['x'].to_list() == [2, 0]
+
+
+def test_hamming_distance_invalid_mode():
+    df = polars.DataFrame({
+        'left': ['a'],
+        'right': ['b'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=hamming_distance('left', 'right', on_length_mismatch='bogus'))
@@ -0,0 +1,62 @@
+from eruo_strutil import hash_string
+import polars
+import pytest
+
+
+def test_hash_string_sha256():
+    df = polars.DataFrame({
+        'input': ['hello', '', None],
+        'expected': [
+            '2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824',
+            'e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855',
+            None,
+        ],
+    })
+    df = df.with_columns(output=hash_string('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_hash_string_xxh3():
+    df = polars.DataFrame({
+        'input': ['hello', None],
+        'expected': ['9555e8555c62dcfd', None],
+    })
+    df = df.with_columns(output=hash_string('input', algorithm='xxh3'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_hash_string_int():
+    df = polars.DataFrame({
+        'input': ['hello', None],
+    })
+    df = df.with_columns(
+        sha256=hash_string('input', output='int'),
+        xxh3=hash_string('input', algorithm='xxh3', output='int'),
+    )
+
+    assert df['sha256'].dtype == polars.UInt64
+    assert df['sha256'].to_list() == [0x2cf24dba5fb0a30e, None]
+    assert df['xxh3'].to_list() == [0x9555e8555c62dcfd, None]
+
+
+def test_hash_string_stable():
+    df = polars.DataFrame({
+        'input': ['user-1', 'user-2', 'user-1'],
+    })
+    first = df.with_columns(output=hash_string('input', algorithm='xxh3'))
+    second = df.with_columns(output=hash_string('input', algorithm='xxh3'))
+
+    assert first['output'].to_list() == second['output'].to_list()
+    assert first['output'][0] == first['output'][2]
+    assert first['output'][0] != first['output'][1]
+
+
+def test_hash_string_invalid_algorithm():
+    df = polars.DataFrame({
+        'input': ['hello'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=hash_string('input', algorithm='md5'))
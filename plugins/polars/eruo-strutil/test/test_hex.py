@@ -0,0 +1,46 @@
+from eruo_strutil import hex_decode, hex_encode
+import polars
+import pytest
+
+
+def test_hex_encode():
+    df = polars.DataFrame({
+        'input': ['hello', 'é', '', None],
+        'expected': ['68656c6c6f', 'c3a9', '', None],
+    })
+    df = df.with_columns(output=hex_encode('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_hex_encode_uppercase():
+    df = polars.DataFrame({
+        'input': ['hello'],
+        'expected': ['68656C6C6F'],
+    })
+    df = df.with_columns(output=hex_encode('input', uppercase=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_hex_round_trip():
+    df = polars.DataFrame({
+        'input': ['héllo wörld', '東京 👋', None],
+    })
+    df = df.with_columns(
+        lowercase=hex_decode(hex_encode('input')),
+        uppercase=hex_decode(hex_encode('input', uppercase=True)),
+    )
+
+    assert df['lowercase'].to_list() == df['input'].to_list()
+    assert df['uppercase'].to_list() == df['input'].to_list()
+
+
+def test_hex_decode_invalid():
+    for value in ['zz', 'abc']:
+        df = polars.DataFrame({
+            'input': [value],
+        })
+
+        with pytest.raises(polars.exceptions.ComputeError):
+            df.with_columns(output=hex_decode('input'))
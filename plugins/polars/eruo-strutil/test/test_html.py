@@ -0,0 +1,41 @@
+from eruo_strutil import html_escape, html_unescape
+import polars
+
+
+def test_html_escape():
+    df = polars.DataFrame({
+        'input': ['<a href="x">Tom & Jerry\'s</a>', 'plain', None],
+        'expected': ['&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#x27;s&lt;/a&gt;', 'plain', None],
+    })
+    df = df.with_columns(output=html_escape('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_html_unescape():
+    df = polars.DataFrame({
+        'input': ['&lt;b&gt;', 'A&nbsp;B', '&#169; &#x1F600;', '&amp;copy; &#169; &#xA9;', None],
+        'expected': ['<b>', 'A\u00a0B', '© \U0001f600', '&copy; © ©', None],
+    })
+    df = df.with_columns(output=html_unescape('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_html_unescape_unknown_entity():
+    df = polars.DataFrame({
+        'input': ['&bogus; &amp; &#xZZ;'],
+        'expected': ['&bogus; & &#xZZ;'],
+    })
+    df = df.with_columns(output=html_unescape('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_html_round_trip():
+    df = polars.DataFrame({
+        'input': ['<p class="a">\'1\' & 2</p>'],
+    })
+    df = df.with_columns(output=html_unescape(html_escape('input')))
+
+    assert df['output'].to_list() == df['input'].to_list()
@@ -0,0 +1,61 @@
+from eruo_strutil import pluralize, singularize
+import polars
+
+
+def test_pluralize_regular():
+    df = polars.DataFrame({
+        'input': ['cat', 'box', 'church', 'city', 'day', 'Bus stop', None],
+        'expected': ['cats', 'boxes', 'churches', 'cities', 'days', 'Bus stops', None],
+    })
+    df = df.with_columns(output=pluralize('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_singularize_regular():
+    df = polars.DataFrame({
+        'input': ['cats', 'boxes', 'churches', 'cities', 'days', 'classes', 'cases'],
+        'expected': ['cat', 'box', 'church', 'city', 'day', 'class', 'case'],
+    })
+    df = df.with_columns(output=singularize('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_inflect_irregular():
+    df = polars.DataFrame({
+        'singular': ['child', 'Person', 'index', 'knife'],
+        'plural': ['children', 'People', 'indices', 'knives'],
+    })
+    df = df.with_columns(
+        pluralized=pluralize('singular'),
+        singularized=singularize('plural'),
+    )
+
+    assert df['pluralized'].to_list() == df['plural'].to_list()
+    assert df['singularized'].to_list() == df['singular'].to_list()
+
+
+def test_inflect_uncountable():
+    df = polars.DataFrame({
+        'input': ['sheep', 'series', 'information'],
+    })
+    df = df.with_columns(
+        pluralized=pluralize('input'),
+        singularized=singularize('input'),
+    )
+
+    assert df['pluralized'].to_list() == df['input'].to_list()
+    assert df['singularized'].to_list() == df['input'].to_list()
+
+
+def test_inflect_extra_irregulars():
+    extra_irregulars = [('octopus', 'octopodes')]
+    df = polars.DataFrame({
+        'input': ['octopus'],
+    })
+    df = df.with_columns(output=pluralize('input', extra_irregulars=extra_irregulars))
+    df = df.with_columns(back=singularize('output', extra_irregulars=extra_irregulars))
+
+    assert df['output'].to_list() == ['octopodes']
+    assert df['back'].to_list() == ['octopus']
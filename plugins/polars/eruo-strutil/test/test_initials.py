@@ -0,0 +1,42 @@
+from eruo_strutil import initials
+import polars
+
+
+def test_initials():
+    df = polars.DataFrame({
+        'input': ['jane q public', '  jane   q  public ', '', None],
+        'expected': ['JQP', 'JQP', '', None],
+    })
+    df = df.with_columns(output=initials('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_initials_leading_number():
+    df = polars.DataFrame({
+        'input': ['3rd street market'],
+        'expected': ['SM'],
+    })
+    df = df.with_columns(output=initials('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_initials_separator():
+    df = polars.DataFrame({
+        'input': ['jane q public'],
+        'expected': ['j.q.p'],
+    })
+    df = df.with_columns(output=initials('input', uppercase=False, separator='.'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_initials_max_count():
+    df = polars.DataFrame({
+        'input': ['jane q public', 'jo'],
+        'expected': ['JQ', 'J'],
+    })
+    df = df.with_columns(output=initials('input', max_count=2))
+
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,35 @@
+from eruo_strutil import is_palindrome
+import polars
+
+
+def test_is_palindrome():
+    df = polars.DataFrame({
+        'input': ['racecar', 'hello', 'a', '', None],
+        'expected': [True, False, True, True, None],
+    })
+    df = df.with_columns(output=is_palindrome('input'))
+
+    assert df['output'].dtype == polars.Boolean
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_is_palindrome_phrase():
+    df = polars.DataFrame({
+        'input': ['A man, a plan, a canal: Panama'],
+    })
+    df = df.with_columns(
+        strict=is_palindrome('input'),
+        relaxed=is_palindrome('input', ignore_case=True, ignore_non_alnum=True),
+    )
+
+    assert df['strict'].to_list() == [False]
+    assert df['relaxed'].to_list() == [True]
+
+
+def test_is_palindrome_graphemes():
+    df = polars.DataFrame({
+        'input': ['e\u0301te\u0301'],
+    })
+    df = df.with_columns(output=is_palindrome('input'))
+
+    assert df['output'].to_list() == [True]
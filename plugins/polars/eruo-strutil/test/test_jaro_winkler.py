@@ -0,0 +1,55 @@
+from eruo_strutil import jaro_winkler
+import polars
+import pytest
+
+
+def test_jaro_winkler():
+    df = polars.DataFrame({
+        'left': ['MARTHA', 'DWAYNE', 'DIXON', 'abc'],
+        'right': ['MARHTA', 'DUANE', 'DICKSONX', 'xyz'],
+        'expected': [0.961, 0.84, 0.813, 0.0],
+    })
+    df = df.with_columns(output=jaro_winkler('left', 'right').round(3))
+
+    assert df['output'].dtype == polars.Float64
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_jaro_winkler_identical():
+    df = polars.DataFrame({
+        'left': ['same', ''],
+        'right': ['same', ''],
+    })
+    df = df.with_columns(output=jaro_winkler('left', 'right'))
+
+    assert df['output'].to_list() == [1.0, 1.0]
+
+
+def test_jaro_winkler_prefix_weight():
+    df = polars.DataFrame({
+        'left': ['MARTHA'],
+        'right': ['MARHTA'],
+    })
+    df = df.with_columns(output=jaro_winkler('left', 'right', prefix_weight=0.0).round(3))
+
+    assert df['output'].to_list() == [0.944]
+
+
+def test_jaro_winkler_null():
+    df = polars.DataFrame({
+        'left': ['MARTHA', None],
+        'right': [None, 'MARHTA'],
+    })
+    df = df.with_columns(output=jaro_winkler('left', 'right'))
+
+    assert df['output'].to_list() == [None, None]
+
+
+def test_jaro_winkler_invalid_prefix_weight():
+    df = polars.DataFrame({
+        'left': ['a'],
+        'right': ['b'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=jaro_winkler('left', 'right', prefix_weight=0.3))
@@ -0,0 +1,42 @@
+from eruo_strutil import to_leetspeak
+import polars
+import pytest
+
+
+def test_to_leetspeak_light():
+    df = polars.DataFrame({
+        'input': ['leetspeak', 'LEET', None],
+        'expected': ['l33tsp34k', 'L33T', None],
+    })
+    df = df.with_columns(output=to_leetspeak('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_to_leetspeak_medium():
+    df = polars.DataFrame({
+        'input': ['leetspeak'],
+        'expected': ['l3375p34k'],
+    })
+    df = df.with_columns(output=to_leetspeak('input', level=2))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_to_leetspeak_heavy():
+    df = polars.DataFrame({
+        'input': ['leetspeak'],
+        'expected': ['13375p34k'],
+    })
+    df = df.with_columns(output=to_leetspeak('input', level=3))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_to_leetspeak_invalid_level():
+    df = polars.DataFrame({
+        'input': ['leetspeak'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=to_leetspeak('input', level=4))
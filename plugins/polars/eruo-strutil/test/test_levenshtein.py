@@ -0,0 +1,34 @@
+from eruo_strutil import levenshtein
+import polars
+
+
+def test_levenshtein():
+    df = polars.DataFrame({
+        'left': ['kitten', 'flaw', '', 'café'],
+        'right': ['sitting', 'lawn', 'abc', 'cafe'],
+        'expected': [3, 2, 3, 1],
+    })
+    df = df.with_columns(output=levenshtein('left', 'right'))
+
+    assert df['output'].dtype == polars.UInt32
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_levenshtein_identical():
+    df = polars.DataFrame({
+        'left': ['same', ''],
+        'right': ['same', ''],
+    })
+    df = df.with_columns(output=levenshtein('left', 'right'))
+
+    assert df['output'].to_list() == [0, 0]
+
+
+def test_levenshtein_null():
+    df = polars.DataFrame({
+        'left': ['kitten', None, 'a'],
+        'right': [None, 'sitting', 'b'],
+    })
+    df = df.with_columns(output=levenshtein('left', 'right'))
+
+    assert df['output'].to_list() == [None, None, 1]
@@ -0,0 +1,48 @@
+from eruo_strutil import longest_common_prefix
+import polars
+
+
+def test_longest_common_prefix():
+    df = polars.DataFrame({
+        'input': ['abcdef', 'abcxyz', None, 'abc'],
+    })
+    df = df.with_columns(output=longest_common_prefix('input'))
+
+    assert df['output'].to_list() == ['abc', 'abc', 'abc', 'abc']
+
+
+def test_longest_common_prefix_nothing_shared():
+    df = polars.DataFrame({
+        'input': ['abc', 'xyz'],
+    })
+    df = df.select(output=longest_common_prefix('input'))
+
+    assert df['output'].to_list() == ['']
+
+
+def test_longest_common_prefix_multibyte():
+    df = polars.DataFrame({
+        'input': ['héllo', 'hèllo', 'héllo'],
+    })
+    df = df.select(output=longest_common_prefix('input'))
+
+    assert df['output'].to_list() == ['h']
+
+
+def test_longest_common_prefix_all_null():
+    df = polars.DataFrame({
+        'input': [None, None],
+    }, schema={'input': polars.String})
+    df = df.select(output=longest_common_prefix('input'))
+
+    assert df['output'].to_list() == ['']
+
+
+def test_longest_common_prefix_group_by():
+    df = polars.DataFrame({
+        'group': ['a', 'a', 'b', 'b'],
+        'input': ['SKU-100', 'SKU-200', 'X1', 'X12'],
+    })
+    df = df.group_by('group', maintain_order=True).agg(output=longest_common_prefix('input'))
+
+    assert df['output'].to_list() == ['SKU-', 'X1']
@@ -0,0 +1,50 @@
+from eruo_strutil import mask
+import polars
+import pytest
+
+
+def test_mask():
+    df = polars.DataFrame({
+        'input': ['1234567890', 'abcde', None],
+        'expected': ['12******90', 'ab*de', None],
+    })
+    df = df.with_columns(output=mask('input', keep_start=2, keep_end=2))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_mask_overlap():
+    df = polars.DataFrame({
+        'input': ['abcd', 'abc'],
+    })
+    df = df.with_columns(output=mask('input', keep_start=2, keep_end=2))
+
+    assert df['output'].to_list() == df['input'].to_list()
+
+
+def test_mask_short_string():
+    df = polars.DataFrame({
+        'input': ['ab', ''],
+    })
+    df = df.with_columns(output=mask('input', keep_start=1, keep_end=1))
+
+    assert df['output'].to_list() == ['ab', '']
+
+
+def test_mask_mask_char():
+    df = polars.DataFrame({
+        'input': ['secret', 'café'],
+        'expected': ['####et', '##fé'],
+    })
+    df = df.with_columns(output=mask('input', keep_end=2, mask_char='#'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_mask_empty_mask_char():
+    df = polars.DataFrame({
+        'input': ['a'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=mask('input', mask_char=''))
@@ -0,0 +1,45 @@
+from eruo_strutil import mask_email
+import polars
+
+
+def test_mask_email():
+    df = polars.DataFrame({
+        'input': ['jdoe@corp.com', 'j@x.io', None],
+        'expected': ['j***@corp.com', 'j@x.io', None],
+    })
+    df = df.with_columns(output=mask_email('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_mask_email_subdomain():
+    df = polars.DataFrame({
+        'input': ['jane.doe@mail.corp.co.uk'],
+        'expected': ['j*******@mail.corp.co.uk'],
+    })
+    df = df.with_columns(output=mask_email('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_mask_email_reveal_first():
+    df = polars.DataFrame({
+        'input': ['jdoe@corp.com'],
+        'expected': ['jd**@corp.com'],
+    })
+    df = df.with_columns(output=mask_email('input', reveal_first=2))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_mask_email_non_email():
+    df = polars.DataFrame({
+        'input': ['not an email', 'a@b@c', '@corp.com'],
+    })
+    df = df.with_columns(
+        default=mask_email('input'),
+        masked=mask_email('input', mask_invalid=True),
+    )
+
+    assert df['default'].to_list() == df['input'].to_list()
+    assert df['masked'].to_list() == ['n***********', 'a****', '@********']
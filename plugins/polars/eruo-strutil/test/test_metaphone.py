@@ -0,0 +1,53 @@
+from eruo_strutil import metaphone
+import polars
+import pytest
+
+
+def test_metaphone():
+    df = polars.DataFrame({
+        'input': ['Knight', 'Phone', 'Wright', 'Xavier', 'Michael', 'Dumb', 'Science', 'School', None],
+        'expected': ['NT', 'FN', 'RT', 'SFR', 'MXL', 'TM', 'SNS', 'SKL', None],
+    })
+    df = df.with_columns(output=metaphone('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_metaphone_mixed_case():
+    df = polars.DataFrame({
+        'input': ['MICHAEL', 'michael', 'mIcHaEl'],
+    })
+    df = df.with_columns(output=metaphone('input'))
+
+    assert df['output'].to_list() == ['MXL', 'MXL', 'MXL']
+
+
+def test_metaphone_max_length():
+    df = polars.DataFrame({
+        'input': ['Whistle', 'Accept'],
+    })
+    df = df.with_columns(
+        short=metaphone('input', max_length=2),
+        long=metaphone('input', max_length=10),
+    )
+
+    assert df['short'].to_list() == ['WS', 'AK']
+    assert df['long'].to_list() == ['WSTL', 'AKSPT']
+
+
+def test_metaphone_non_alphabetic():
+    df = polars.DataFrame({
+        'input': ['123', '', '--'],
+    })
+    df = df.with_columns(output=metaphone('input'))
+
+    assert df['output'].to_list() == [None, None, None]
+
+
+def test_metaphone_invalid_max_length():
+    df = polars.DataFrame({
+        'input': ['a'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=metaphone('input', max_length=0))
@@ -0,0 +1,47 @@
+from eruo_strutil import morse_decode, morse_encode
+import polars
+import pytest
+
+
+def test_morse_encode():
+    df = polars.DataFrame({
+        'input': ['SOS', 'Hello World', '', None],
+        'expected': ['... --- ...', '.... . .-.. .-.. --- / .-- --- .-. .-.. -..', '', None],
+    })
+    df = df.with_columns(output=morse_encode('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_morse_round_trip():
+    df = polars.DataFrame({
+        'input': ['SOS', 'HELLO WORLD'],
+    })
+    df = df.with_columns(output=morse_decode(morse_encode('input')))
+
+    assert df['output'].to_list() == df['input'].to_list()
+
+
+def test_morse_custom_separators():
+    df = polars.DataFrame({
+        'input': ['HELLO WORLD'],
+    })
+    df = df.with_columns(
+        encoded=morse_encode('input', letter_sep='|', word_sep='  '),
+        decoded=morse_decode(morse_encode('input', letter_sep='|', word_sep='  '), letter_sep='|', word_sep='  '),
+    )
+
+    assert df['encoded'].to_list() == ['....|.|.-..|.-..|---  .--|---|.-.|.-..|-..']
+    assert df['decoded'].to_list() == ['HELLO WORLD']
+
+
+def test_morse_unsupported():
+    df = polars.DataFrame({
+        'input': ['café'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=morse_encode('input'))
+
+    df = df.with_columns(output=morse_encode('input', skip_unsupported=True))
+    assert df['output'].to_list() == ['-.-. .- ..-.']
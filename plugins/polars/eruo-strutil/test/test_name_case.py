@@ -0,0 +1,42 @@
+from eruo_strutil import to_name_case
+import polars
+
+
+def test_to_name_case():
+    df = polars.DataFrame({
+        'input': ['john smith', 'JANE DOE', "o'brien", 'mcdonald-smith', None],
+        'expected': ['John Smith', 'Jane Doe', "O'Brien", 'McDonald-Smith', None],
+    })
+    df = df.with_columns(output=to_name_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_to_name_case_mac_prefix():
+    df = polars.DataFrame({
+        'input': ['macdonald', 'mack', 'machado'],
+        'expected': ['MacDonald', 'Mack', 'Machado'],
+    })
+    df = df.with_columns(output=to_name_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_to_name_case_possessive():
+    df = polars.DataFrame({
+        'input': ["john's", 'd’angelo'],
+        'expected': ["John's", 'D’Angelo'],
+    })
+    df = df.with_columns(output=to_name_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_to_name_case_without_mc_rule():
+    df = polars.DataFrame({
+        'input': ['mcdonald-smith', 'macdonald'],
+        'expected': ['Mcdonald-Smith', 'Macdonald'],
+    })
+    df = df.with_columns(output=to_name_case('input', mc_rule=False))
+
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,58 @@
+from eruo_strutil import ngrams
+import polars
+import pytest
+
+
+def test_ngrams_word_bigrams():
+    df = polars.DataFrame({
+        'input': ['the quick  brown fox', None],
+    })
+    df = df.with_columns(output=ngrams('input', n=2))
+
+    assert df['output'].to_list() == [
+        ['the quick', 'quick brown', 'brown fox'],
+        None,
+    ]
+
+
+def test_ngrams_separator():
+    df = polars.DataFrame({
+        'input': ['a b c'],
+    })
+    df = df.with_columns(output=ngrams('input', n=2, separator='_'))
+
+    assert df['output'].to_list() == [['a_b', 'b_c']]
+
+
+def test_ngrams_char_trigrams():
+    df = polars.DataFrame({
+        'input': ['hello', 'abc'],
+    })
+    df = df.with_columns(output=ngrams('input', n=3, char_level=True))
+
+    assert df['output'].to_list() == [
+        ['hel', 'ell', 'llo'],
+        ['abc'],
+    ]
+
+
+def test_ngrams_short_input():
+    df = polars.DataFrame({
+        'input': ['one', 'ab', ''],
+    })
+    df = df.with_columns(
+        words=ngrams('input', n=2),
+        chars=ngrams('input', n=3, char_level=True),
+    )
+
+    assert df['words'].to_list() == [[], [], []]
+    assert df['chars'].to_list() == [['one'], [], []]
+
+
+def test_ngrams_zero():
+    df = polars.DataFrame({
+        'input': ['a'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=ngrams('input', n=0))
@@ -0,0 +1,45 @@
+from eruo_strutil import normalize_nfc, normalize_nfd, normalize_nfkc, normalize_nfkd
+import polars
+
+PRECOMPOSED = 'caf\u00e9'
+DECOMPOSED = 'cafe\u0301'
+
+
+def test_normalize_nfc():
+    df = polars.DataFrame({
+        'input': [PRECOMPOSED, DECOMPOSED, None],
+        'expected': [PRECOMPOSED, PRECOMPOSED, None],
+    })
+    df = df.with_columns(output=normalize_nfc('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_normalize_nfd():
+    df = polars.DataFrame({
+        'input': [PRECOMPOSED, DECOMPOSED, None],
+        'expected': [DECOMPOSED, DECOMPOSED, None],
+    })
+    df = df.with_columns(output=normalize_nfd('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_normalize_nfkc():
+    df = polars.DataFrame({
+        'input': ['\ufb01nance', DECOMPOSED, None],
+        'expected': ['finance', PRECOMPOSED, None],
+    })
+    df = df.with_columns(output=normalize_nfkc('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_normalize_nfkd():
+    df = polars.DataFrame({
+        'input': ['\ufb01nance', PRECOMPOSED, None],
+        'expected': ['finance', DECOMPOSED, None],
+    })
+    df = df.with_columns(output=normalize_nfkd('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,45 @@
+from eruo_strutil import normalize_phone
+import polars
+import pytest
+
+
+def test_normalize_phone():
+    df = polars.DataFrame({
+        'input': ['(555) 123-4567', '555.123.4567', '1-555-123-4567', None],
+        'expected': ['+15551234567', '+15551234567', '+15551234567', None],
+    })
+    df = df.with_columns(output=normalize_phone('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_normalize_phone_international():
+    df = polars.DataFrame({
+        'input': ['+44 20 7946 0958', '0044 20 7946 0958'],
+        'expected': ['+442079460958', '+442079460958'],
+    })
+    df = df.with_columns(output=normalize_phone('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_normalize_phone_default_country_code():
+    df = polars.DataFrame({
+        'input': ['020 7946 0958'],
+        'expected': ['442079460958'],
+    })
+    df = df.with_columns(output=normalize_phone('input', default_country_code='44', output_format='digits'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_normalize_phone_invalid():
+    df = polars.DataFrame({
+        'input': ['12345'],
+    })
+    df = df.with_columns(output=normalize_phone('input'))
+
+    assert df['output'].to_list() == [None]
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=normalize_phone('input', strict=True))
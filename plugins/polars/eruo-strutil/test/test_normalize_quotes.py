@@ -0,0 +1,46 @@
+from eruo_strutil import normalize_quotes
+import polars
+
+
+def test_normalize_quotes_to_straight():
+    df = polars.DataFrame({
+        'input': ['“Hello,” she said', '‘quoted’', 'plain "text"', None],
+        'expected': ['"Hello," she said', "'quoted'", 'plain "text"', None],
+    })
+    df = df.with_columns(output=normalize_quotes('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_normalize_quotes_to_curly():
+    df = polars.DataFrame({
+        'input': ['"Hello," she said', "'quoted'", '("nested")'],
+        'expected': ['“Hello,” she said', '‘quoted’', '(“nested”)'],
+    })
+    df = df.with_columns(output=normalize_quotes('input', to_straight=False))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_normalize_quotes_with_apostrophes():
+    df = polars.DataFrame({
+        'input': ['“Don’t go,” he said, ‘it’s late’'],
+    })
+    df = df.with_columns(straight=normalize_quotes('input'))
+    df = df.with_columns(curly=normalize_quotes('straight', to_straight=False))
+
+    assert df['straight'].to_list() == ['"Don\'t go," he said, \'it\'s late\'']
+    assert df['curly'].to_list() == df['input'].to_list()
+
+
+def test_normalize_quotes_dashes():
+    df = polars.DataFrame({
+        'input': ['wait — what – now', 'wait -- what - now'],
+    })
+    df = df.with_columns(
+        straight=normalize_quotes('input', dashes=True),
+        curly=normalize_quotes('input', to_straight=False, dashes=True),
+    )
+
+    assert df['straight'].to_list() == ['wait - what - now', 'wait -- what - now']
+    assert df['curly'].to_list() == ['wait — what – now', 'wait — what – now']
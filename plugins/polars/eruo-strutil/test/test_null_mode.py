@@ -0,0 +1,225 @@
+import eruo_strutil as strx
+import polars
+import pytest
+
+# Each expression with the arguments it needs, applied to a frame of text columns
+STRING_EXPRESSIONS = {
+    'pig_latinnify': lambda **kwargs: strx.pig_latinnify('input', **kwargs),
+    'split_by_chars': lambda **kwargs: strx.split_by_chars('input', characters=',', **kwargs),
+    'split_by_regex': lambda **kwargs: strx.split_by_regex('input', pattern=',', **kwargs),
+    'to_sentence_case': lambda **kwargs: strx.to_sentence_case('input', **kwargs),
+    'to_sponge_case': lambda **kwargs: strx.to_sponge_case('input', seed=0, **kwargs),
+    'to_camel_case': lambda **kwargs: strx.to_camel_case('input', **kwargs),
+    'to_pascal_case': lambda **kwargs: strx.to_pascal_case('input', **kwargs),
+    'to_snake_case': lambda **kwargs: strx.to_snake_case('input', **kwargs),
+    'to_kebab_case': lambda **kwargs: strx.to_kebab_case('input', **kwargs),
+    'to_constant_case': lambda **kwargs: strx.to_constant_case('input', **kwargs),
+    'to_title_case': lambda **kwargs: strx.to_title_case('input', **kwargs),
+    'to_swap_case': lambda **kwargs: strx.to_swap_case('input', **kwargs),
+    'normalize_nfc': lambda **kwargs: strx.normalize_nfc('input', **kwargs),
+    'normalize_nfd': lambda **kwargs: strx.normalize_nfd('input', **kwargs),
+    'normalize_nfkc': lambda **kwargs: strx.normalize_nfkc('input', **kwargs),
+    'normalize_nfkd': lambda **kwargs: strx.normalize_nfkd('input', **kwargs),
+    'remove_diacritics': lambda **kwargs: strx.remove_diacritics('input', **kwargs),
+    'slugify': lambda **kwargs: strx.slugify('input', **kwargs),
+    'base64_encode': lambda **kwargs: strx.base64_encode('input', **kwargs),
+    'base64_decode': lambda **kwargs: strx.base64_decode('encoded', **kwargs),
+    'hex_encode': lambda **kwargs: strx.hex_encode('input', **kwargs),
+    'hex_decode': lambda **kwargs: strx.hex_decode('hex', **kwargs),
+    'url_encode': lambda **kwargs: strx.url_encode('input', **kwargs),
+    'url_decode': lambda **kwargs: strx.url_decode('input', **kwargs),
+    'html_escape': lambda **kwargs: strx.html_escape('input', **kwargs),
+    'html_unescape': lambda **kwargs: strx.html_unescape('input', **kwargs),
+    'replace_regex': lambda **kwargs: strx.replace_regex('input', pattern='o', replacement='0', **kwargs),
+    'extract_regex': lambda **kwargs: strx.extract_regex('input', pattern='(o)', **kwargs),
+    'extract_all_regex': lambda **kwargs: strx.extract_all_regex('input', pattern='o', **kwargs),
+    'pad_start': lambda **kwargs: strx.pad_start('input', length=12, **kwargs),
+    'pad_end': lambda **kwargs: strx.pad_end('input', length=12, **kwargs),
+    'center': lambda **kwargs: strx.center('input', length=12, **kwargs),
+    'trim_chars': lambda **kwargs: strx.trim_chars('input', **kwargs),
+    'ltrim_chars': lambda **kwargs: strx.ltrim_chars('input', **kwargs),
+    'rtrim_chars': lambda **kwargs: strx.rtrim_chars('input', **kwargs),
+    'squeeze_whitespace': lambda **kwargs: strx.squeeze_whitespace('input', **kwargs),
+    'reverse_string': lambda **kwargs: strx.reverse_string('input', **kwargs),
+    'word_count': lambda **kwargs: strx.word_count('input', **kwargs),
+    'grapheme_len': lambda **kwargs: strx.grapheme_len('input', **kwargs),
+    'truncate_ellipsis': lambda **kwargs: strx.truncate_ellipsis('input', length=5, **kwargs),
+    'wrap_text': lambda **kwargs: strx.wrap_text('input', width=5, **kwargs),
+    'levenshtein': lambda **kwargs: strx.levenshtein('input', 'other', **kwargs),
+    'jaro_winkler': lambda **kwargs: strx.jaro_winkler('input', 'other', **kwargs),
+    'hamming_distance': lambda **kwargs: strx.hamming_distance('input', 'other', on_length_mismatch='null', **kwargs),
+    'soundex': lambda **kwargs: strx.soundex('input', **kwargs),
+    'metaphone': lambda **kwargs: strx.metaphone('input', **kwargs),
+    'ngrams': lambda **kwargs: strx.ngrams('input', n=2, **kwargs),
+    'count_substring': lambda **kwargs: strx.count_substring('input', needle='o', **kwargs),
+    'find_all_positions': lambda **kwargs: strx.find_all_positions('input', needle='o', **kwargs),
+    'mask': lambda **kwargs: strx.mask('input', **kwargs),
+    'mask_email': lambda **kwargs: strx.mask_email('input', **kwargs),
+    'extract_emails': lambda **kwargs: strx.extract_emails('input', **kwargs),
+    'extract_urls': lambda **kwargs: strx.extract_urls('input', **kwargs),
+    'extract_numbers': lambda **kwargs: strx.extract_numbers('input', **kwargs),
+    'roman_decode': lambda **kwargs: strx.roman_decode('input', strict=False, **kwargs),
+    'repeat': lambda **kwargs: strx.repeat('input', count=2, **kwargs),
+    'strip_html': lambda **kwargs: strx.strip_html('input', **kwargs),
+    'remove_emoji': lambda **kwargs: strx.remove_emoji('input', **kwargs),
+    'extract_emoji': lambda **kwargs: strx.extract_emoji('input', **kwargs),
+    'strip_zero_width': lambda **kwargs: strx.strip_zero_width('input', **kwargs),
+    'rot_n': lambda **kwargs: strx.rot_n('input', **kwargs),
+    'is_palindrome': lambda **kwargs: strx.is_palindrome('input', **kwargs),
+    'initials': lambda **kwargs: strx.initials('input', **kwargs),
+    'to_ascii': lambda **kwargs: strx.to_ascii('input', **kwargs),
+    'remove_suffix': lambda **kwargs: strx.remove_suffix('input', suffix='o', **kwargs),
+    'remove_prefix': lambda **kwargs: strx.remove_prefix('input', prefix='h', **kwargs),
+    'detect_case': lambda **kwargs: strx.detect_case('input', **kwargs),
+    'caseless_equals': lambda **kwargs: strx.caseless_equals('input', 'other', **kwargs),
+    'similarity_ratio': lambda **kwargs: strx.similarity_ratio('input', 'other', **kwargs),
+    'tokenize': lambda **kwargs: strx.tokenize('input', **kwargs),
+    'morse_encode': lambda **kwargs: strx.morse_encode('input', **kwargs),
+    'morse_decode': lambda **kwargs: strx.morse_decode('morse', **kwargs),
+    'to_leetspeak': lambda **kwargs: strx.to_leetspeak('input', **kwargs),
+    'to_fullwidth': lambda **kwargs: strx.to_fullwidth('input', **kwargs),
+    'to_halfwidth': lambda **kwargs: strx.to_halfwidth('input', **kwargs),
+    'expand_contractions': lambda **kwargs: strx.expand_contractions('input', **kwargs),
+    'normalize_quotes': lambda **kwargs: strx.normalize_quotes('input', **kwargs),
+    'char_stats': lambda **kwargs: strx.char_stats('input', **kwargs),
+    'count_vowels': lambda **kwargs: strx.count_vowels('input', **kwargs),
+    'count_consonants': lambda **kwargs: strx.count_consonants('input', **kwargs),
+    'syllable_count': lambda **kwargs: strx.syllable_count('input', **kwargs),
+    'flesch_reading_ease': lambda **kwargs: strx.flesch_reading_ease('input', **kwargs),
+    'detect_script': lambda **kwargs: strx.detect_script('input', **kwargs),
+    'redact_pii': lambda **kwargs: strx.redact_pii('input', **kwargs),
+    'fuzzy_contains': lambda **kwargs: strx.fuzzy_contains('input', needle='wrld', **kwargs),
+    'split_words': lambda **kwargs: strx.split_words('input', **kwargs),
+    'search_key': lambda **kwargs: strx.search_key('input', **kwargs),
+    'pluralize': lambda **kwargs: strx.pluralize('input', **kwargs),
+    'singularize': lambda **kwargs: strx.singularize('input', **kwargs),
+    'to_name_case': lambda **kwargs: strx.to_name_case('input', **kwargs),
+    'to_alternating_case': lambda **kwargs: strx.to_alternating_case('input', **kwargs),
+    'strip_accents': lambda **kwargs: strx.strip_accents('input', **kwargs),
+    'fit_width': lambda **kwargs: strx.fit_width('input', width=12, **kwargs),
+    'top_keywords': lambda **kwargs: strx.top_keywords('input', **kwargs),
+    'normalize_phone': lambda **kwargs: strx.normalize_phone('input', **kwargs),
+    'parse_bool': lambda **kwargs: strx.parse_bool('input', **kwargs),
+    'hash_string': lambda **kwargs: strx.hash_string('input', **kwargs),
+    'base32_encode': lambda **kwargs: strx.base32_encode('input', **kwargs),
+    'base32_decode': lambda **kwargs: strx.base32_decode('base32', **kwargs),
+    'fix_mojibake': lambda **kwargs: strx.fix_mojibake('input', **kwargs),
+    'split_fixed': lambda **kwargs: strx.split_fixed('input', separator=' ', n=2, **kwargs),
+    'chunk': lambda **kwargs: strx.chunk('input', size=3, **kwargs),
+    'group_digits': lambda **kwargs: strx.group_digits('input', **kwargs),
+    'dedupe_tokens': lambda **kwargs: strx.dedupe_tokens('input', **kwargs),
+    'sort_tokens': lambda **kwargs: strx.sort_tokens('input', **kwargs),
+    'anagram_key': lambda **kwargs: strx.anagram_key('input', **kwargs),
+    'best_match': lambda **kwargs: strx.best_match('input', candidates=['hello'], **kwargs),
+}
+
+INTEGER_EXPRESSIONS = {
+    'number_to_words': lambda **kwargs: strx.number_to_words('input', **kwargs),
+    'ordinalize': lambda **kwargs: strx.ordinalize('input', **kwargs),
+    'roman_encode': lambda **kwargs: strx.roman_encode('input', **kwargs),
+}
+
+BINARY_EXPRESSIONS = {
+    'guess_encoding': lambda **kwargs: strx.guess_encoding('input', **kwargs),
+    'decode_bytes': lambda **kwargs: strx.decode_bytes('input', encoding='utf-8', **kwargs),
+}
+
+
+def string_frame() -> polars.DataFrame:
+    # The first row has a value in every column, the second row is null in every column,
+    # and the last row holds empty strings to compare the "empty" mode against
+    return polars.DataFrame({
+        'input': ['hello world', None, ''],
+        'other': ['hello there', None, ''],
+        'encoded': ['aGVsbG8=', None, ''],
+        'hex': ['68656c6c6f', None, ''],
+        'morse': ['.... . .-.. .-.. ---', None, ''],
+        'base32': ['NBSWY3DP', None, ''],
+    })
+
+
+def binary_frame() -> polars.DataFrame:
+    return polars.DataFrame({'input': [b'hello world', None, b'']}, schema={'input': polars.Binary})
+
+
+@pytest.mark.parametrize('name', STRING_EXPRESSIONS)
+def test_null_mode_propagate(name):
+    expression = STRING_EXPRESSIONS[name]
+    df = string_frame().with_columns(
+        default=expression(),
+        propagate=expression(null_mode='propagate'),
+    )
+
+    assert df['default'][1] is None
+    assert df['propagate'].to_list() == df['default'].to_list()
+
+
+@pytest.mark.parametrize('name', STRING_EXPRESSIONS)
+def test_null_mode_empty(name):
+    expression = STRING_EXPRESSIONS[name]
+    df = string_frame().with_columns(output=expression(null_mode='empty'))
+
+    output = df['output'].to_list()
+    assert output[1] == output[2]
+
+
+@pytest.mark.parametrize('name', STRING_EXPRESSIONS)
+def test_null_mode_error(name):
+    expression = STRING_EXPRESSIONS[name]
+    df = string_frame()
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=expression(null_mode='error'))
+
+    df = df.drop_nulls().with_columns(output=expression(null_mode='error'))
+    assert df['output'].len() == 2
+
+
+@pytest.mark.parametrize('name', INTEGER_EXPRESSIONS)
+def test_null_mode_integer_input(name):
+    expression = INTEGER_EXPRESSIONS[name]
+    df = polars.DataFrame({'input': [3, None]}).with_columns(
+        propagate=expression(),
+        empty=expression(null_mode='empty'),
+    )
+
+    assert df['propagate'][1] is None
+    assert df['empty'][1] == ''
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=expression(null_mode='error'))
+
+
+@pytest.mark.parametrize('name', BINARY_EXPRESSIONS)
+def test_null_mode_binary_input(name):
+    expression = BINARY_EXPRESSIONS[name]
+    df = binary_frame().with_columns(
+        propagate=expression(),
+        empty=expression(null_mode='empty'),
+    )
+
+    assert df['propagate'][1] is None
+    assert df['empty'][1] == df['empty'][2]
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=expression(null_mode='error'))
+
+    df = binary_frame().drop_nulls().with_columns(output=expression(null_mode='error'))
+    assert df['output'].len() == 2
+
+
+def test_null_mode_longest_common_prefix():
+    df = polars.DataFrame({'input': ['hello', None, 'help']})
+
+    assert df.select(output=strx.longest_common_prefix('input'))['output'].to_list() == ['hel']
+    assert df.select(output=strx.longest_common_prefix('input', null_mode='empty'))['output'].to_list() == ['']
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.select(output=strx.longest_common_prefix('input', null_mode='error'))
+
+
+def test_null_mode_invalid():
+    df = polars.DataFrame({'input': ['hello', None]})
+
+    with pytest.raises(polars.exceptions.InvalidOperationError):
+        df.with_columns(output=strx.to_snake_case('input', null_mode='skip'))
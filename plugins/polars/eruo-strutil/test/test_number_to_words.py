@@ -0,0 +1,63 @@
+from eruo_strutil import number_to_words
+import polars
+import pytest
+
+
+def test_number_to_words():
+    df = polars.DataFrame({
+        'input': [0, 7, 13, 42, 100, 101, 1234, 1_000_000, 2_000_000_005, None],
+        'expected': [
+            'zero',
+            'seven',
+            'thirteen',
+            'forty-two',
+            'one hundred',
+            'one hundred one',
+            'one thousand two hundred thirty-four',
+            'one million',
+            'two billion five',
+            None,
+        ],
+    })
+    df = df.with_columns(output=number_to_words('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_number_to_words_negative():
+    df = polars.DataFrame({
+        'input': [-1, -215],
+        'expected': ['minus one', 'minus two hundred fifteen'],
+    })
+    df = df.with_columns(output=number_to_words('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_number_to_words_use_and():
+    df = polars.DataFrame({
+        'input': [101, 1001, 1234],
+        'expected': ['one hundred and one', 'one thousand and one', 'one thousand two hundred and thirty-four'],
+    })
+    df = df.with_columns(output=number_to_words('input', use_and=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_number_to_words_numeric_strings():
+    df = polars.DataFrame({
+        'input': ['1234', '-5'],
+        'expected': ['one thousand two hundred thirty-four', 'minus five'],
+    })
+    df = df.with_columns(output=number_to_words('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_number_to_words_too_large():
+    df = polars.DataFrame({
+        'input': [1_000_000_000_000_000],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=number_to_words('input'))
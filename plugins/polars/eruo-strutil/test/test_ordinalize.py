@@ -0,0 +1,42 @@
+from eruo_strutil import ordinalize
+import polars
+
+
+def test_ordinalize():
+    df = polars.DataFrame({
+        'input': [1, 2, 3, 4, 21, 22, 23, 101, None],
+        'expected': ['1st', '2nd', '3rd', '4th', '21st', '22nd', '23rd', '101st', None],
+    })
+    df = df.with_columns(output=ordinalize('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_ordinalize_teens():
+    df = polars.DataFrame({
+        'input': [11, 12, 13, 111, 112, 113],
+        'expected': ['11th', '12th', '13th', '111th', '112th', '113th'],
+    })
+    df = df.with_columns(output=ordinalize('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_ordinalize_multiples_of_ten():
+    df = polars.DataFrame({
+        'input': [0, 10, 20, 100],
+        'expected': ['0th', '10th', '20th', '100th'],
+    })
+    df = df.with_columns(output=ordinalize('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_ordinalize_negative():
+    df = polars.DataFrame({
+        'input': [-1, -12, -23],
+        'expected': ['-1st', '-12th', '-23rd'],
+    })
+    df = df.with_columns(output=ordinalize('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
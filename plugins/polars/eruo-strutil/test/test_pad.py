@@ -0,0 +1,61 @@
+from eruo_strutil import pad_end, pad_start
+import polars
+import pytest
+
+
+def test_pad_start():
+    df = polars.DataFrame({
+        'input': ['7', '42', '1234', None],
+        'expected': ['007', '042', '1234', None],
+    })
+    df = df.with_columns(output=pad_start('input', length=3, fill='0'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_pad_end():
+    df = polars.DataFrame({
+        'input': ['abc', '', None],
+        'expected': ['abc  ', '     ', None],
+    })
+    df = df.with_columns(output=pad_end('input', length=5))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_pad_multi_char_fill():
+    df = polars.DataFrame({
+        'input': ['abc', 'café'],
+    })
+    df = df.with_columns(
+        start=pad_start('input', length=8, fill='xy'),
+        end=pad_end('input', length=8, fill='xy'),
+    )
+
+    assert df['start'].to_list() == ['xyxyxabc', 'xyxycafé']
+    assert df['end'].to_list() == ['abcxyxyx', 'caféxyxy']
+
+
+def test_pad_longer_than_length():
+    df = polars.DataFrame({
+        'input': ['abcdefghij'],
+    })
+    df = df.with_columns(
+        start=pad_start('input', length=4, fill='-'),
+        end=pad_end('input', length=4, fill='-'),
+    )
+
+    assert df['start'].to_list() == df['input'].to_list()
+    assert df['end'].to_list() == df['input'].to_list()
+
+
+def test_pad_empty_fill():
+    df = polars.DataFrame({
+        'input': ['a'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=pad_start('input', length=3, fill=''))
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=pad_end('input', length=3, fill=''))
@@ -0,0 +1,24 @@
+from eruo_strutil import replace_regex, split_by_chars, to_sentence_case, to_snake_case
+import polars
+
+# Large enough to be split across the thread pool
+ROW_COUNT = 100_000
+
+SAMPLES = ['helloWorld, foo', None, 'XMLHttpRequest 42', 'the end. the start', '']
+
+
+def test_parallel_output_matches_row_order():
+    samples = polars.DataFrame({'input': SAMPLES})
+    df = polars.DataFrame({'input': SAMPLES * (ROW_COUNT // len(SAMPLES))})
+
+    expressions = {
+        'sentence': to_sentence_case('input'),
+        'snake': to_snake_case('input'),
+        'replaced': replace_regex('input', pattern=r'\d+', replacement='#'),
+        'parts': split_by_chars('input', characters=','),
+    }
+    expected = samples.with_columns(**expressions)
+    output = df.with_columns(**expressions)
+
+    for name in expressions:
+        assert output[name].to_list() == expected[name].to_list() * (ROW_COUNT // len(SAMPLES))
@@ -0,0 +1,39 @@
+from eruo_strutil import parse_bool
+import polars
+import pytest
+
+
+@pytest.mark.parametrize('truthy, falsy', [
+    ('yes', 'no'),
+    ('y', 'n'),
+    ('true', 'false'),
+    ('t', 'f'),
+    ('1', '0'),
+    ('on', 'off'),
+])
+def test_parse_bool(truthy, falsy):
+    df = polars.DataFrame({
+        'input': [truthy, falsy, truthy.upper(), f' {falsy.capitalize()} ', None],
+        'expected': [True, False, True, False, None],
+    })
+    df = df.with_columns(output=parse_bool('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_parse_bool_unrecognized():
+    df = polars.DataFrame({
+        'input': ['yes', 'maybe', ''],
+    })
+    df = df.with_columns(output=parse_bool('input'))
+
+    assert df['output'].to_list() == [True, None, None]
+
+
+def test_parse_bool_unrecognized_strict():
+    df = polars.DataFrame({
+        'input': ['yes', 'maybe'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=parse_bool('input', strict=True))
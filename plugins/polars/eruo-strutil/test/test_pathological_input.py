@@ -0,0 +1,52 @@
+import eruo_strutil as strx
+import polars
+import pytest
+
+PATHOLOGICAL_VALUES = ['', ' ', '!', '...', '-', "'", '@', '&#;', '\u0301', '\u200d', 'ß', 'İ']
+
+EXPRESSIONS = {
+    'pig_latinnify': strx.pig_latinnify('input'),
+    'to_sentence_case': strx.to_sentence_case('input'),
+    'to_camel_case': strx.to_camel_case('input'),
+    'to_title_case': strx.to_title_case('input'),
+    'slugify': strx.slugify('input', max_length=1),
+    'html_unescape': strx.html_unescape('input'),
+    'truncate_ellipsis': strx.truncate_ellipsis('input', length=1, word_boundary=True),
+    'wrap_text': strx.wrap_text('input', width=1),
+    'soundex': strx.soundex('input'),
+    'metaphone': strx.metaphone('input'),
+    'ngrams': strx.ngrams('input', n=1, char_level=True),
+    'mask': strx.mask('input', keep_start=1, keep_end=1),
+    'mask_email': strx.mask_email('input', mask_invalid=True),
+    'extract_numbers': strx.extract_numbers('input', allow_thousands_separator=True),
+    'strip_html': strx.strip_html('input'),
+    'initials': strx.initials('input'),
+    'detect_case': strx.detect_case('input'),
+    'count_substring': strx.count_substring('input', needle='ß', case_insensitive=True, overlapping=True),
+    'remove_suffix': strx.remove_suffix('input', suffix='ss', case_insensitive=True),
+}
+
+
+@pytest.mark.parametrize('name', EXPRESSIONS)
+def test_pathological_input_does_not_panic(name):
+    df = polars.DataFrame({'input': PATHOLOGICAL_VALUES})
+    df = df.with_columns(output=EXPRESSIONS[name])
+
+    assert df['output'].len() == len(PATHOLOGICAL_VALUES)
+
+
+def test_pig_latinnify_lone_punctuation():
+    df = polars.DataFrame({
+        'input': ['', '!', '...', 'hello!'],
+        'expected': ['', '!', '...', 'ellohay!'],
+    })
+    df = df.with_columns(output=strx.pig_latinnify('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_repeat_too_long():
+    df = polars.DataFrame({'input': ['abc']})
+
+    with pytest.raises(polars.exceptions.ComputeError, match="repeating 'abc'"):
+        df.with_columns(output=strx.repeat('input', count=2_000_000_000))
@@ -27,4 +27,60 @@ def test_pig_latinnify():
     })
     df = df.with_columns(output=pig_latinnify('input'))
 
-    assert df['output'].to_list() == df['expected'].to_list()
\ No newline at end of file
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_pig_latinnify_multibyte():
+    df = polars.DataFrame({
+        'input': [
+            'café!',
+            'naïve',
+            'hello…',
+            'çava',
+            'Ñandu',
+            'rhythm',
+            '¡!',
+        ],
+        'expected': [
+            'afécay!',
+            'aïvenay',
+            'ellohay…',
+            'avaçay',
+            'AnduÑay',
+            'rhythmay',
+            '¡!',
+        ],
+    })
+    df = df.with_columns(output=pig_latinnify('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_pig_latinnify_yay_dialect():
+    df = polars.DataFrame({
+        'input': ['is', 'apple', 'banana'],
+        'expected': ['isyay', 'appleyay', 'ananabay'],
+    })
+    df = df.with_columns(output=pig_latinnify('input', vowel_suffix='yay'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_pig_latinnify_treat_y_as_vowel():
+    df = polars.DataFrame({
+        'input': ['yellow', 'Yes', 'rhythm', 'black'],
+        'expected': ['yellowway', 'Yesway', 'rhythmay', 'ackblay'],
+    })
+    df = df.with_columns(output=pig_latinnify('input', treat_y_as_vowel=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_pig_latinnify_custom_suffixes():
+    df = polars.DataFrame({
+        'input': ['is', 'this'],
+        'expected': ['ishay', 'isthoy'],
+    })
+    df = df.with_columns(output=pig_latinnify('input', vowel_suffix='hay', consonant_suffix='oy'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
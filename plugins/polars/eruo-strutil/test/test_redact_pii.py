@@ -0,0 +1,52 @@
+from eruo_strutil import redact_pii
+import polars
+import pytest
+
+
+def test_redact_pii():
+    df = polars.DataFrame({
+        'input': [
+            'Mail john@example.com or call 555-123-4567.',
+            'SSN 123-45-6789',
+            'order 12345 shipped on 2024-01-02',
+            None,
+        ],
+        'expected': [
+            'Mail **************** or call ************.',
+            'SSN ***********',
+            'order 12345 shipped on 2024-01-02',
+            None,
+        ],
+    })
+    df = df.with_columns(output=redact_pii('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_redact_pii_selected_types():
+    df = polars.DataFrame({
+        'input': ['john@example.com, (555) 123 4567, 123-45-6789'],
+        'expected': ['john@example.com, ##############, ###########'],
+    })
+    df = df.with_columns(output=redact_pii('input', redact_emails=False, mask_char='#'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_redact_pii_digits_inside_email():
+    df = polars.DataFrame({
+        'input': ['5551234567@example.com'],
+        'expected': ['**********************'],
+    })
+    df = df.with_columns(output=redact_pii('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_redact_pii_empty_mask_char():
+    df = polars.DataFrame({
+        'input': ['john@example.com'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=redact_pii('input', mask_char=''))
@@ -0,0 +1,48 @@
+from eruo_strutil import remove_prefix, remove_suffix
+import polars
+
+
+def test_remove_suffix():
+    df = polars.DataFrame({
+        'input': ['data.csv', 'notes.txt', 'csv', None],
+        'expected': ['data', 'notes.txt', 'csv', None],
+    })
+    df = df.with_columns(output=remove_suffix('input', suffix='.csv'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_remove_suffix_case_insensitive():
+    df = polars.DataFrame({
+        'input': ['data.csv', 'data.CSV'],
+    })
+    df = df.with_columns(
+        sensitive=remove_suffix('input', suffix='.csv'),
+        insensitive=remove_suffix('input', suffix='.csv', case_insensitive=True),
+    )
+
+    assert df['sensitive'].to_list() == ['data', 'data.CSV']
+    assert df['insensitive'].to_list() == ['data', 'data']
+
+
+def test_remove_prefix():
+    df = polars.DataFrame({
+        'input': ['https://a.io', 'ftp://c.io', 'http', None],
+        'expected': ['a.io', 'ftp://c.io', 'http', None],
+    })
+    df = df.with_columns(output=remove_prefix('input', prefix='https://'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_remove_prefix_case_insensitive():
+    df = polars.DataFrame({
+        'input': ['https://a.io', 'HTTPS://b.io'],
+    })
+    df = df.with_columns(
+        sensitive=remove_prefix('input', prefix='https://'),
+        insensitive=remove_prefix('input', prefix='https://', case_insensitive=True),
+    )
+
+    assert df['sensitive'].to_list() == ['a.io', 'HTTPS://b.io']
+    assert df['insensitive'].to_list() == ['a.io', 'b.io']
@@ -0,0 +1,28 @@
+from eruo_strutil import remove_diacritics
+import polars
+
+
+def test_remove_diacritics():
+    df = polars.DataFrame({
+        'input': [
+            'Mötley Crüe',
+            'Ångström façade',
+            'cafe\u0301',
+            'already ascii',
+            '北京市',
+            '한국어',
+            None,
+        ],
+        'expected': [
+            'Motley Crue',
+            'Angstrom facade',
+            'cafe',
+            'already ascii',
+            '北京市',
+            '한국어',
+            None,
+        ],
+    })
+    df = df.with_columns(output=remove_diacritics('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,40 @@
+from eruo_strutil import repeat
+import polars
+
+
+def test_repeat():
+    df = polars.DataFrame({
+        'input': ['=', 'ab', None],
+        'expected': ['=====', 'ababababab', None],
+    })
+    df = df.with_columns(output=repeat('input', count=5))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_repeat_separator():
+    df = polars.DataFrame({
+        'input': ['ab', ''],
+        'expected': ['ab-ab-ab', '--'],
+    })
+    df = df.with_columns(output=repeat('input', count=3, separator='-'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_repeat_zero():
+    df = polars.DataFrame({
+        'input': ['ab'],
+    })
+    df = df.with_columns(output=repeat('input', count=0, separator='-'))
+
+    assert df['output'].to_list() == ['']
+
+
+def test_repeat_once():
+    df = polars.DataFrame({
+        'input': ['ab'],
+    })
+    df = df.with_columns(output=repeat('input', count=1, separator='-'))
+
+    assert df['output'].to_list() == ['ab']
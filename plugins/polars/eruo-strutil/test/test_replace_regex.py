@@ -0,0 +1,57 @@
+from eruo_strutil import replace_regex
+import polars
+import pytest
+
+
+def test_replace_regex():
+    df = polars.DataFrame({
+        'input': ['a1b2c3', 'no digits', None],
+        'expected': ['a<1>b<2>c<3>', 'no digits', None],
+    })
+    df = df.with_columns(output=replace_regex('input', pattern=r'(\d)', replacement='<$1>'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_replace_regex_named_groups():
+    df = polars.DataFrame({
+        'input': ['2024-01-15', 'born 1999-12-31'],
+        'expected': ['15/01/2024', 'born 31/12/1999'],
+    })
+    df = df.with_columns(output=replace_regex('input',
+                                              pattern=r'(?P<y>\d{4})-(?P<m>\d{2})-(?P<d>\d{2})',
+                                              replacement='${d}/${m}/${y}'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_replace_regex_first_only():
+    df = polars.DataFrame({
+        'input': ['a1b2c3'],
+        'expected': ['a<1>b2c3'],
+    })
+    df = df.with_columns(output=replace_regex('input', pattern=r'(\d)', replacement='<$1>', replace_all=False))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_replace_regex_case_insensitive():
+    df = polars.DataFrame({
+        'input': ['Cat cat CAT'],
+    })
+    df = df.with_columns(
+        sensitive=replace_regex('input', pattern='cat', replacement='dog'),
+        insensitive=replace_regex('input', pattern='cat', replacement='dog', case_insensitive=True),
+    )
+
+    assert df['sensitive'].to_list() == ['Cat dog CAT']
+    assert df['insensitive'].to_list() == ['dog dog dog']
+
+
+def test_replace_regex_invalid_pattern():
+    df = polars.DataFrame({
+        'input': ['a'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=replace_regex('input', pattern='(', replacement=''))
@@ -0,0 +1,47 @@
+from eruo_strutil import reverse_string
+import polars
+
+
+def test_reverse_string():
+    df = polars.DataFrame({
+        'input': ['hello', 'racecar', '', None],
+        'expected': ['olleh', 'racecar', '', None],
+    })
+    df = df.with_columns(output=reverse_string('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_reverse_string_combining_mark():
+    value = 'cafe\u0301'
+    df = polars.DataFrame({
+        'input': [value],
+    })
+    df = df.with_columns(output=reverse_string('input'))
+
+    # A naive reverse moves the accent onto the wrong letter
+    assert value[::-1] == '\u0301efac'
+    assert df['output'].to_list() == ['e\u0301fac']
+
+
+def test_reverse_string_flag_emoji():
+    value = 'id\U0001f1ee\U0001f1e9jp\U0001f1ef\U0001f1f5'
+    df = polars.DataFrame({
+        'input': [value],
+    })
+    df = df.with_columns(output=reverse_string('input'))
+
+    # A naive reverse swaps the regional indicators into different flags
+    assert value[::-1] == '\U0001f1f5\U0001f1efpj\U0001f1e9\U0001f1eedi'
+    assert df['output'].to_list() == ['\U0001f1ef\U0001f1f5pj\U0001f1ee\U0001f1e9di']
+
+
+def test_reverse_string_zwj_sequence():
+    family = '\U0001f468\u200d\U0001f469\u200d\U0001f467'
+    df = polars.DataFrame({
+        'input': ['a' + family + 'b'],
+        'expected': ['b' + family + 'a'],
+    })
+    df = df.with_columns(output=reverse_string('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,76 @@
+from eruo_strutil import roman_decode, roman_encode
+import polars
+import pytest
+
+
+def test_roman_encode():
+    df = polars.DataFrame({
+        'input': [1, 3, 8, 1994, 3999, None],
+        'expected': ['I', 'III', 'VIII', 'MCMXCIV', 'MMMCMXCIX', None],
+    })
+    df = df.with_columns(output=roman_encode('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_roman_subtractive():
+    df = polars.DataFrame({
+        'input': [4, 9, 40, 90, 400, 900],
+        'numeral': ['IV', 'IX', 'XL', 'XC', 'CD', 'CM'],
+    })
+    df = df.with_columns(
+        encoded=roman_encode('input'),
+        decoded=roman_decode('numeral'),
+    )
+
+    assert df['encoded'].to_list() == df['numeral'].to_list()
+    assert df['decoded'].to_list() == df['input'].to_list()
+
+
+def test_roman_round_trip():
+    df = polars.DataFrame({
+        'input': list(range(1, 4000)),
+    })
+    df = df.with_columns(output=roman_decode(roman_encode('input')))
+
+    assert df['output'].to_list() == df['input'].to_list()
+
+
+def test_roman_decode_case_insensitive():
+    df = polars.DataFrame({
+        'input': ['mcmxciv', 'Xl', None],
+        'expected': [1994, 40, None],
+    })
+    df = df.with_columns(output=roman_decode('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_roman_encode_out_of_range():
+    for value in [0, 4000, -1]:
+        df = polars.DataFrame({
+            'input': [value],
+        })
+
+        with pytest.raises(polars.exceptions.ComputeError):
+            df.with_columns(output=roman_encode('input'))
+
+
+def test_roman_decode_malformed():
+    for value in ['IIII', 'VX', 'IC', 'ABC', '']:
+        df = polars.DataFrame({
+            'input': [value],
+        })
+
+        with pytest.raises(polars.exceptions.ComputeError):
+            df.with_columns(output=roman_decode('input'))
+
+
+def test_roman_decode_malformed_non_strict():
+    df = polars.DataFrame({
+        'input': ['IIII', 'VX', 'X'],
+        'expected': [None, None, 10],
+    })
+    df = df.with_columns(output=roman_decode('input', strict=False))
+
+    assert df['output'].to_list() == df['expected'].to_list()
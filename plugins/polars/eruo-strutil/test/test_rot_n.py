@@ -0,0 +1,36 @@
+from eruo_strutil import rot_n
+import polars
+
+
+def test_rot_n_rot13():
+    df = polars.DataFrame({
+        'input': ['Hello, World!', 'café 123', None],
+        'expected': ['Uryyb, Jbeyq!', 'pnsé 123', None],
+    })
+    df = df.with_columns(
+        output=rot_n('input'),
+        round_trip=rot_n(rot_n('input')),
+    )
+
+    assert df['output'].to_list() == df['expected'].to_list()
+    assert df['round_trip'].to_list() == df['input'].to_list()
+
+
+def test_rot_n_shift_one():
+    df = polars.DataFrame({
+        'input': ['abc XYZ'],
+        'expected': ['bcd YZA'],
+    })
+    df = df.with_columns(output=rot_n('input', shift=1))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_rot_n_negative_shift():
+    df = polars.DataFrame({
+        'input': ['Khoor'],
+        'expected': ['Hello'],
+    })
+    df = df.with_columns(output=rot_n('input', shift=-3))
+
+    assert df['output'].to_list() == df['expected'].to_list()
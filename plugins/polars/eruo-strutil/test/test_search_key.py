@@ -0,0 +1,38 @@
+from eruo_strutil import search_key
+import polars
+
+
+def test_search_key():
+    df = polars.DataFrame({
+        'input': ['  Café   RENÉ ', 'Straße', 'Ｎｏ１', '', None],
+        'expected': ['cafe rene', 'strasse', 'no1', '', None],
+    })
+    df = df.with_columns(output=search_key('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_search_key_disabled_stages():
+    df = polars.DataFrame({
+        'input': ['  Café   RENÉ '],
+    })
+    df = df.with_columns(
+        keep_diacritics=search_key('input', remove_diacritics=False),
+        keep_case=search_key('input', case_fold=False),
+        keep_whitespace=search_key('input', squeeze_whitespace=False),
+    )
+
+    assert df['keep_diacritics'].to_list() == ['café rené']
+    assert df['keep_case'].to_list() == ['Cafe RENE']
+    assert df['keep_whitespace'].to_list() == ['  cafe   rene ']
+
+
+def test_search_key_matches_join_keys():
+    left = polars.DataFrame({'name': ['Café René', 'Zoë']})
+    right = polars.DataFrame({'name': ['  CAFE rene', 'ZOE  ']})
+    df = left.with_columns(key=search_key('name')).join(
+        right.with_columns(key=search_key('name')),
+        on='key',
+    )
+
+    assert df.height == 2
@@ -0,0 +1,37 @@
+from eruo_strutil import similarity_ratio
+import polars
+import pytest
+
+
+def test_similarity_ratio():
+    # Expected values from difflib.SequenceMatcher(None, left, right).ratio()
+    df = polars.DataFrame({
+        'left': ['abcd', 'kitten', 'hello', 'apple'],
+        'right': ['bcde', 'sitting', 'world', 'apple'],
+        'expected': [0.75, 8 / 13, 0.2, 1.0],
+    })
+    df = df.with_columns(output=similarity_ratio('left', 'right'))
+
+    assert df['output'].dtype == polars.Float64
+    assert df['output'].to_list() == pytest.approx(df['expected'].to_list())
+
+
+def test_similarity_ratio_empty():
+    df = polars.DataFrame({
+        'left': ['', 'abc', ''],
+        'right': ['', '', 'abc'],
+        'expected': [1.0, 0.0, 0.0],
+    })
+    df = df.with_columns(output=similarity_ratio('left', 'right'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_similarity_ratio_null():
+    df = polars.DataFrame({
+        'left': ['abc', None],
+        'right': [None, 'abc'],
+    })
+    df = df.with_columns(output=similarity_ratio('left', 'right'))
+
+    assert df['output'].to_list() == [None, None]
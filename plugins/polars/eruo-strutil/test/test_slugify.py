@@ -0,0 +1,51 @@
+from eruo_strutil import slugify
+import polars
+
+
+def test_slugify():
+    df = polars.DataFrame({
+        'input': [
+            'Héllo, World! ',
+            '  Mötley   Crüe -- Live  ',
+            'already-a-slug',
+            '!!! ???',
+            '',
+        ],
+        'expected': [
+            'hello-world',
+            'motley-crue-live',
+            'already-a-slug',
+            '',
+            '',
+        ],
+    })
+    df = df.with_columns(output=slugify('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_slugify_separator_and_case():
+    df = polars.DataFrame({
+        'input': ['Héllo, World! '],
+        'expected': ['Hello_World'],
+    })
+    df = df.with_columns(output=slugify('input', separator='_', lowercase=False))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_slugify_max_length():
+    df = polars.DataFrame({
+        'input': ['hello world'] * 4,
+    })
+    df = df.with_columns(
+        at_separator=slugify('input', max_length=6),
+        mid_separator=slugify('input', separator='--', max_length=6),
+        after_separator=slugify('input', separator='--', max_length=8),
+        mid_word=slugify('input', max_length=3),
+    )
+
+    assert df['at_separator'].to_list() == ['hello'] * 4
+    assert df['mid_separator'].to_list() == ['hello'] * 4
+    assert df['after_separator'].to_list() == ['hello--w'] * 4
+    assert df['mid_word'].to_list() == ['hel'] * 4
@@ -0,0 +1,44 @@
+from eruo_strutil import sort_tokens
+import polars
+
+
+def test_sort_tokens():
+    df = polars.DataFrame({
+        'input': ['b,a,c', 'c, a ,b', '10,2,1', None],
+        'expected': ['a,b,c', 'a,b,c', '1,10,2', None],
+    })
+    df = df.with_columns(output=sort_tokens('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_sort_tokens_descending():
+    df = polars.DataFrame({
+        'input': ['b; a; c'],
+    })
+    df = df.with_columns(output=sort_tokens('input', '; ', descending=True))
+
+    assert df['output'].to_list() == ['c; b; a']
+
+
+def test_sort_tokens_numeric():
+    df = polars.DataFrame({
+        'input': ['10,2,1', '1.5,-2,10', 'x,10,2'],
+        'expected': ['1,2,10', '-2,1.5,10', '2,10,x'],
+    })
+    df = df.with_columns(output=sort_tokens('input', numeric=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_sort_tokens_case_insensitive():
+    df = polars.DataFrame({
+        'input': ['banana,Apple,cherry', 'b,B,a,A'],
+    })
+    df = df.with_columns(
+        case_sensitive=sort_tokens('input'),
+        case_insensitive=sort_tokens('input', case_insensitive=True),
+    )
+
+    assert df['case_sensitive'].to_list() == ['Apple,banana,cherry', 'A,B,a,b']
+    assert df['case_insensitive'].to_list() == ['Apple,banana,cherry', 'A,a,B,b']
@@ -0,0 +1,22 @@
+from eruo_strutil import soundex
+import polars
+
+
+def test_soundex():
+    df = polars.DataFrame({
+        'input': ['Robert', 'Rupert', 'Rubin', 'Tymczak', 'Pfister', 'Ashcraft', 'Honeyman', 'Lee'],
+        'expected': ['R163', 'R163', 'R150', 'T522', 'P236', 'A261', 'H555', 'L000'],
+    })
+    df = df.with_columns(output=soundex('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_soundex_non_alphabetic():
+    df = polars.DataFrame({
+        'input': ["  o'brien", 'Müller', '123', '', None],
+        'expected': ['O165', 'M460', None, None, None],
+    })
+    df = df.with_columns(output=soundex('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -14,9 +14,56 @@ def test_split_by_chars():
     output = polars.select(split_by_chars(input.get_column('input'), characters=',;')).to_series().to_list()
 
     expected = [
-        'Nadya Arina', 'Refal Hady', 'Giorgino Abraham', 'Anggika Bolsterli', 'Laura Theux', 'Christine Hakim',
-        'Maudy Koesnaedi', 'Rano Karno', 'Cornelia Agatha', 'Mandra Naih', 'Aminah Tjendrakasih', 'Suty Karno',
-        'Shay Mitchell', 'Liza Soberano', 'Jon Jon Briones', 'Darren Criss', 'Manny Jacinto', 'Dante Basco',
+        ['Nadya Arina', 'Refal Hady', 'Giorgino Abraham', 'Anggika Bolsterli', 'Laura Theux', 'Christine Hakim'],
+        ['Maudy Koesnaedi', 'Rano Karno', 'Cornelia Agatha', 'Mandra Naih', 'Aminah Tjendrakasih', 'Suty Karno'],
+        ['Shay Mitchell', 'Liza Soberano', 'Jon Jon Briones', 'Darren Criss', 'Manny Jacinto', 'Dante Basco'],
     ]
 
-    assert output == expected
\ No newline at end of file
+    assert output == expected
+
+
+def test_split_by_chars_preserves_rows():
+    df = polars.DataFrame({
+        'input': ['a, b', None, 'c'],
+    })
+    df = df.with_columns(output=split_by_chars('input', characters=','))
+
+    assert df.height == 3
+    assert df['output'].dtype == polars.List(polars.String)
+    assert df['output'].to_list() == [['a', 'b'], None, ['c']]
+
+
+def test_split_by_chars_keep_empty():
+    df = polars.DataFrame({
+        'input': ['a,,b, ,c', ',a,b', 'a,b,', ',,'],
+    })
+    df = df.with_columns(output=split_by_chars('input', characters=','))
+
+    assert df['output'].to_list() == [['a', '', 'b', '', 'c'], ['', 'a', 'b'], ['a', 'b', ''], ['', '', '']]
+
+
+def test_split_by_chars_drop_empty():
+    df = polars.DataFrame({
+        'input': ['a,,b, ,c', ',a,b', 'a,b,', ',,'],
+    })
+    df = df.with_columns(output=split_by_chars('input', characters=',', keep_empty=False))
+
+    assert df['output'].to_list() == [['a', 'b', 'c'], ['a', 'b'], ['a', 'b'], []]
+
+
+def test_split_by_chars_maxsplit():
+    df = polars.DataFrame({
+        'input': ['2025-01-01 INFO Server started on port 8080', 'a,b,c,d', 'a', None],
+    })
+    df = df.with_columns(output=split_by_chars('input', characters=' ,', maxsplit=1))
+
+    assert df['output'].to_list() == [['2025-01-01', 'INFO Server started on port 8080'], ['a', 'b,c,d'], ['a'], None]
+
+
+def test_split_by_chars_maxsplit_drop_empty():
+    df = polars.DataFrame({
+        'input': [',,a,,b,c', 'a, ,b,c'],
+    })
+    df = df.with_columns(output=split_by_chars('input', characters=',', keep_empty=False, maxsplit=1))
+
+    assert df['output'].to_list() == [['a', 'b,c'], ['a', 'b,c']]
@@ -0,0 +1,30 @@
+from eruo_strutil import split_by_regex
+import polars
+import pytest
+
+
+def test_split_by_regex():
+    df = polars.DataFrame({
+        'input': [
+            'Nadya Arina , Refal Hady,Giorgino Abraham',
+            'Maudy Koesnaedi',
+            None,
+        ],
+    })
+    df = df.with_columns(output=split_by_regex('input', pattern=r'\s*,\s*'))
+
+    assert df.height == 3
+    assert df['output'].to_list() == [
+        ['Nadya Arina', 'Refal Hady', 'Giorgino Abraham'],
+        ['Maudy Koesnaedi'],
+        None,
+    ]
+
+
+def test_split_by_regex_invalid_pattern():
+    df = polars.DataFrame({
+        'input': ['a,b'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=split_by_regex('input', pattern='('))
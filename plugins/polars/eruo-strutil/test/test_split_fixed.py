@@ -0,0 +1,54 @@
+from eruo_strutil import split_fixed
+import polars
+import pytest
+
+
+def test_split_fixed():
+    df = polars.DataFrame({
+        'input': ['a,b,c', 'x,y,z', None],
+    })
+    df = df.with_columns(output=split_fixed('input', ',', 3)).unnest('output')
+
+    assert df['field_0'].to_list() == ['a', 'x', None]
+    assert df['field_1'].to_list() == ['b', 'y', None]
+    assert df['field_2'].to_list() == ['c', 'z', None]
+
+
+def test_split_fixed_under_filled():
+    df = polars.DataFrame({
+        'input': ['a,b', 'a', ''],
+    })
+    df = df.with_columns(
+        null=split_fixed('input', ',', 3),
+        filled=split_fixed('input', ',', 3, fill='-'),
+    )
+
+    assert df['null'].to_list() == [
+        {'field_0': 'a', 'field_1': 'b', 'field_2': None},
+        {'field_0': 'a', 'field_1': None, 'field_2': None},
+        {'field_0': '', 'field_1': None, 'field_2': None},
+    ]
+    assert df['filled'].to_list() == [
+        {'field_0': 'a', 'field_1': 'b', 'field_2': '-'},
+        {'field_0': 'a', 'field_1': '-', 'field_2': '-'},
+        {'field_0': '', 'field_1': '-', 'field_2': '-'},
+    ]
+
+
+def test_split_fixed_over_filled():
+    df = polars.DataFrame({
+        'input': ['2024 - 01 - 15 - extra', 'a - b - c'],
+    })
+    df = df.with_columns(output=split_fixed('input', ' - ', 2)).unnest('output')
+
+    assert df['field_0'].to_list() == ['2024', 'a']
+    assert df['field_1'].to_list() == ['01 - 15 - extra', 'b - c']
+
+
+def test_split_fixed_invalid_n():
+    df = polars.DataFrame({
+        'input': ['a,b'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=split_fixed('input', ',', 0))
@@ -0,0 +1,32 @@
+from eruo_strutil import split_words
+import polars
+
+
+def test_split_words():
+    df = polars.DataFrame({
+        'input': ['helloWorld', 'snake_case_name', 'kebab-case name', '', None],
+        'expected': [['hello', 'World'], ['snake', 'case', 'name'], ['kebab', 'case', 'name'], [], None],
+    })
+    df = df.with_columns(output=split_words('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_split_words_acronyms():
+    df = polars.DataFrame({
+        'input': ['XMLHttpRequest', 'getHTTPResponse', 'ABC'],
+        'expected': [['XML', 'Http', 'Request'], ['get', 'HTTP', 'Response'], ['ABC']],
+    })
+    df = df.with_columns(output=split_words('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_split_words_digits():
+    df = polars.DataFrame({
+        'input': ['parseJSON2Data', 'v2', 'base64Encode'],
+        'expected': [['parse', 'JSON', '2', 'Data'], ['v', '2'], ['base', '64', 'Encode']],
+    })
+    df = df.with_columns(output=split_words('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
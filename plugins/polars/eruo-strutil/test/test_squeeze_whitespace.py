@@ -0,0 +1,42 @@
+from eruo_strutil import squeeze_whitespace
+import polars
+import pytest
+
+
+def test_squeeze_whitespace():
+    df = polars.DataFrame({
+        'input': ['  hello    world  ', 'a\t\tb', 'line\n\nbreak', 'non\u00a0\u00a0breaking', '   ', None],
+        'expected': ['hello world', 'a b', 'line break', 'non breaking', '', None],
+    })
+    df = df.with_columns(output=squeeze_whitespace('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_squeeze_whitespace_mixed_runs():
+    df = polars.DataFrame({
+        'input': [' \t\n a \u00a0\t b \r\n'],
+        'expected': ['a b'],
+    })
+    df = df.with_columns(output=squeeze_whitespace('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_squeeze_whitespace_keep_char():
+    df = polars.DataFrame({
+        'input': ['--a---b-', 'a  b'],
+        'expected': ['a-b', 'a  b'],
+    })
+    df = df.with_columns(output=squeeze_whitespace('input', keep_char='-'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_squeeze_whitespace_invalid_keep_char():
+    df = polars.DataFrame({
+        'input': ['a'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=squeeze_whitespace('input', keep_char='ab'))
@@ -0,0 +1,42 @@
+from eruo_strutil import strip_accents
+import polars
+import pytest
+
+
+def test_strip_accents():
+    df = polars.DataFrame({
+        'input': ['Peña José', 'Français', None],
+        'expected': ['Pena Jose', 'Francais', None],
+    })
+    df = df.with_columns(output=strip_accents('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_strip_accents_preserve():
+    df = polars.DataFrame({
+        'input': ['Peña José', 'PEÑA', 'François Désiré'],
+        'expected': ['Peña Jose', 'PEÑA', 'François Desire'],
+    })
+    df = df.with_columns(output=strip_accents('input', preserve=['ñ', 'ç']))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_strip_accents_preserve_decomposed_input():
+    df = polars.DataFrame({
+        'input': ['Pen\u0303a Jose\u0301'],
+        'expected': ['Peña Jose'],
+    })
+    df = df.with_columns(output=strip_accents('input', preserve=['ñ']))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_strip_accents_invalid_preserve():
+    df = polars.DataFrame({
+        'input': ['Peña'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=strip_accents('input', preserve=['ñe']))
@@ -0,0 +1,45 @@
+from eruo_strutil import strip_html
+import polars
+
+
+def test_strip_html_nested_tags():
+    df = polars.DataFrame({
+        'input': ['<div><p>Hello <b>wor</b>ld</p><p>Second <i><u>line</u></i></p></div>', 'plain   text', None],
+        'expected': ['Hello world Second line', 'plain text', None],
+    })
+    df = df.with_columns(output=strip_html('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_strip_html_self_closing_tags():
+    df = polars.DataFrame({
+        'input': ['first<br/>second<br />third <img src="x.png" alt="x"/>end'],
+        'expected': ['first second third end'],
+    })
+    df = df.with_columns(output=strip_html('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_strip_html_entities():
+    df = polars.DataFrame({
+        'input': ['<p>Tom &amp; Jerry &lt;3 &#169;</p>'],
+        'expected': ['Tom & Jerry <3 ©'],
+    })
+    df = df.with_columns(output=strip_html('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_strip_html_script_style():
+    df = polars.DataFrame({
+        'input': ['<style>p { color: red; }</style><p>Text<!-- note --> here</p><script>alert(1);</script>'],
+    })
+    df = df.with_columns(
+        dropped=strip_html('input'),
+        kept=strip_html('input', drop_script_style=False),
+    )
+
+    assert df['dropped'].to_list() == ['Text here']
+    assert df['kept'].to_list() == ['p { color: red; } Text here alert(1);']
@@ -0,0 +1,46 @@
+from eruo_strutil import strip_zero_width
+import polars
+
+
+def test_strip_zero_width():
+    df = polars.DataFrame({
+        'input': ['id\u200b123', 'soft\u00adhyphen', 'plain', None],
+        'expected': ['id123', 'softhyphen', 'plain', None],
+    })
+    df = df.with_columns(output=strip_zero_width('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_strip_zero_width_bom():
+    df = polars.DataFrame({
+        'input': ['\ufeffname'],
+        'expected': ['name'],
+    })
+    df = df.with_columns(output=strip_zero_width('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_strip_zero_width_keeps_emoji_joiner():
+    family = '\U0001f468\u200d\U0001f469\u200d\U0001f467'
+    df = polars.DataFrame({
+        'input': [f'a\u200db {family}'],
+        'expected': [f'ab {family}'],
+    })
+    df = df.with_columns(output=strip_zero_width('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_strip_zero_width_directional():
+    df = polars.DataFrame({
+        'input': ['\u200eabc\u200f'],
+    })
+    df = df.with_columns(
+        default=strip_zero_width('input'),
+        directional=strip_zero_width('input', strip_directional=True),
+    )
+
+    assert df['default'].to_list() == df['input'].to_list()
+    assert df['directional'].to_list() == ['abc']
@@ -0,0 +1,32 @@
+from eruo_strutil import syllable_count
+import polars
+
+
+def test_syllable_count():
+    df = polars.DataFrame({
+        'input': ['cake', 'beautiful', 'free', 'table', '', None],
+        'expected': [1, 3, 1, 2, 0, None],
+    })
+    df = df.with_columns(output=syllable_count('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_syllable_count_multiple_words():
+    df = polars.DataFrame({
+        'input': ['The cake is beautiful!'],
+        'expected': [6],
+    })
+    df = df.with_columns(output=syllable_count('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_syllable_count_per_word():
+    df = polars.DataFrame({
+        'input': ['The cake is beautiful!', '', None],
+        'expected': [[1, 1, 1, 3], [], None],
+    })
+    df = df.with_columns(output=syllable_count('input', per_word=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
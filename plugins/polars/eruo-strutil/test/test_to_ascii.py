@@ -0,0 +1,35 @@
+from eruo_strutil import to_ascii
+import polars
+
+
+def test_to_ascii_latin():
+    df = polars.DataFrame({
+        'input': ['Björk Guðmundsdóttir', 'Æsir straße', 'plain', None],
+        'expected': ['Bjork Gudmundsdottir', 'AEsir strasse', 'plain', None],
+    })
+    df = df.with_columns(output=to_ascii('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_to_ascii_other_scripts():
+    df = polars.DataFrame({
+        'input': ['Фёдор Достоевский', '北京'],
+        'expected': ['Fiodor Dostoevskii', 'Bei Jing'],
+    })
+    df = df.with_columns(output=to_ascii('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_to_ascii_replacement():
+    df = polars.DataFrame({
+        'input': ['a\ue000b'],
+    })
+    df = df.with_columns(
+        default=to_ascii('input'),
+        custom=to_ascii('input', replacement='[?]'),
+    )
+
+    assert df['default'].to_list() == ['a?b']
+    assert df['custom'].to_list() == ['a[?]b']
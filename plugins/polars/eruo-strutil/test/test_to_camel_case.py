@@ -0,0 +1,30 @@
+from eruo_strutil import to_camel_case
+import polars
+
+
+def test_to_camel_case():
+    df = polars.DataFrame({
+        'input': [
+            'hello world',
+            'foo_bar-baz',
+            'XMLHttpRequest',
+            'camelCase',
+            'PascalCase',
+            'CONSTANT_CASE',
+            '  leading and trailing  ',
+            '',
+        ],
+        'expected': [
+            'helloWorld',
+            'fooBarBaz',
+            'xmlHttpRequest',
+            'camelCase',
+            'pascalCase',
+            'constantCase',
+            'leadingAndTrailing',
+            '',
+        ],
+    })
+    df = df.with_columns(output=to_camel_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
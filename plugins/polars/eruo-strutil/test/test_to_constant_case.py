@@ -0,0 +1,28 @@
+from eruo_strutil import to_constant_case
+import polars
+
+
+def test_to_constant_case():
+    df = polars.DataFrame({
+        'input': [
+            'Max Retry Count',
+            'MAX_RETRY_COUNT',
+            'HTTP SERVER',
+            'maxRetryCount',
+            "don't stop!",
+            'retry 3 times',
+            'v2 API',
+        ],
+        'expected': [
+            'MAX_RETRY_COUNT',
+            'MAX_RETRY_COUNT',
+            'HTTP_SERVER',
+            'MAX_RETRY_COUNT',
+            'DONT_STOP',
+            'RETRY_3_TIMES',
+            'V2_API',
+        ],
+    })
+    df = df.with_columns(output=to_constant_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
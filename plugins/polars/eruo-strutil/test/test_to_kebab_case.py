@@ -0,0 +1,31 @@
+from eruo_strutil import to_kebab_case
+import polars
+
+
+def test_to_kebab_case():
+    df = polars.DataFrame({
+        'input': [
+            'HelloWorld',
+            'hello_world',
+            'hello--world',
+            '-hello - world-',
+            'parseJSONData',
+            '2-Fast-Furious',
+            '404NotFound',
+            '3d printer',
+        ],
+        'expected': [
+            'hello-world',
+            'hello-world',
+            'hello-world',
+            'hello-world',
+            'parse-json-data',
+            '2-fast-furious',
+            '404-not-found',
+            '3d-printer',
+        ],
+    })
+    df = df.with_columns(output=to_kebab_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+    assert not df['output'].str.contains('--').any()
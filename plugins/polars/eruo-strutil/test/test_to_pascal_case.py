@@ -0,0 +1,32 @@
+from eruo_strutil import to_pascal_case
+import polars
+
+
+def test_to_pascal_case():
+    df = polars.DataFrame({
+        'input': [
+            'some-value',
+            'some_value',
+            'some value',
+            '__some value',
+            '-some-value-',
+            'JSON parser',
+            'XMLHttpRequest',
+            'version2point0',
+            'camelCase',
+        ],
+        'expected': [
+            'SomeValue',
+            'SomeValue',
+            'SomeValue',
+            'SomeValue',
+            'SomeValue',
+            'JsonParser',
+            'XmlHttpRequest',
+            'Version2point0',
+            'CamelCase',
+        ],
+    })
+    df = df.with_columns(output=to_pascal_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
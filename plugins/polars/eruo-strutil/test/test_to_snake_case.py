@@ -0,0 +1,32 @@
+from eruo_strutil import to_snake_case
+import polars
+
+
+def test_to_snake_case():
+    df = polars.DataFrame({
+        'input': [
+            'HelloWorld',
+            'hello world',
+            'hello-world',
+            'parseJSONData',
+            '__hello  -- world__',
+            'hello.World_foo-bar baz',
+            'utf8Encoding',
+            'HTML5Parser',
+            'user ID 2 value',
+        ],
+        'expected': [
+            'hello_world',
+            'hello_world',
+            'hello_world',
+            'parse_json_data',
+            'hello_world',
+            'hello_world_foo_bar_baz',
+            'utf8_encoding',
+            'html5_parser',
+            'user_id_2_value',
+        ],
+    })
+    df = df.with_columns(output=to_snake_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
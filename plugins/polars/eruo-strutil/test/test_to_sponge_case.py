@@ -1,5 +1,6 @@
 from eruo_strutil import to_sponge_case
 import polars
+import pytest
 
 
 def test_to_sponge_case():
@@ -8,4 +9,37 @@ def test_to_sponge_case():
     })
     df = df.with_columns(output=to_sponge_case('input'))
 
-    assert True # no idea how to test this
\ No newline at end of file
+    assert True # no idea how to test this
+
+
+def test_to_sponge_case_seed():
+    df = polars.DataFrame({
+        'input': ['lorem. ipsum! dolor? sit amet.', 'consectetur adipiscing elit'],
+    })
+    first = df.with_columns(output=to_sponge_case('input', seed=42))
+    second = df.with_columns(output=to_sponge_case('input', seed=42))
+
+    assert first['output'].to_list() == second['output'].to_list()
+    assert first['output'].str.to_lowercase().to_list() == df['input'].to_list()
+
+
+def test_to_sponge_case_upper_probability():
+    df = polars.DataFrame({
+        'input': ['lorem. ipsum! dolor? sit amet.'],
+    })
+    df = df.with_columns(
+        upper=to_sponge_case('input', upper_probability=1.0),
+        lower=to_sponge_case('input', upper_probability=0.0),
+    )
+
+    assert df['upper'].to_list() == ['LOREM. IPSUM! DOLOR? SIT AMET.']
+    assert df['lower'].to_list() == ['lorem. ipsum! dolor? sit amet.']
+
+
+def test_to_sponge_case_invalid_upper_probability():
+    df = polars.DataFrame({
+        'input': ['lorem'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=to_sponge_case('input', upper_probability=1.5))
@@ -0,0 +1,26 @@
+from eruo_strutil import to_swap_case
+import polars
+
+
+def test_to_swap_case():
+    df = polars.DataFrame({
+        'input': [
+            'Hello World!',
+            'sWAP cASE 123',
+            'Straße',
+            'İstanbul',
+            'ıi',
+            '東京',
+        ],
+        'expected': [
+            'hELLO wORLD!',
+            'Swap Case 123',
+            'sTRASSE',
+            'i\u0307STANBUL',
+            'II',
+            '東京',
+        ],
+    })
+    df = df.with_columns(output=to_swap_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
@@ -0,0 +1,38 @@
+from eruo_strutil import to_title_case
+import polars
+
+
+def test_to_title_case():
+    df = polars.DataFrame({
+        'input': [
+            'the lord of the rings',
+            'a tale of two cities',
+            'what are you looking at',
+            'THE GREAT GATSBY',
+            'the',
+            '  war  and   peace ',
+            '(the) end of an era',
+        ],
+        'expected': [
+            'The Lord of the Rings',
+            'A Tale of Two Cities',
+            'What Are You Looking At',
+            'The Great Gatsby',
+            'The',
+            '  War  and   Peace ',
+            '(The) End of an Era',
+        ],
+    })
+    df = df.with_columns(output=to_title_case('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_to_title_case_custom_stop_words():
+    df = polars.DataFrame({
+        'input': ['the lord of the rings'],
+        'expected': ['The Lord Of the Rings'],
+    })
+    df = df.with_columns(output=to_title_case('input', stop_words=['the']))
+
+    assert df['output'].to_list() == df['expected'].to_list()
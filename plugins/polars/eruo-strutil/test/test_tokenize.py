@@ -0,0 +1,38 @@
+from eruo_strutil import tokenize
+import polars
+
+
+def test_tokenize():
+    df = polars.DataFrame({
+        'input': ['The Quick, brown fox -- is OK!', '', None],
+    })
+    df = df.with_columns(output=tokenize('input'))
+
+    assert df['output'].to_list() == [['the', 'quick', 'brown', 'fox', 'is', 'ok'], [], None]
+
+
+def test_tokenize_min_token_len():
+    df = polars.DataFrame({
+        'input': ['The Quick, brown fox -- is OK!'],
+    })
+    df = df.with_columns(output=tokenize('input', lowercase=False, min_token_len=3))
+
+    assert df['output'].to_list() == [['The', 'Quick', 'brown', 'fox']]
+
+
+def test_tokenize_combined_filters():
+    df = polars.DataFrame({
+        'input': ['The Quick, brown fox -- is OK!', 'A tale of two cities'],
+    })
+    df = df.with_columns(output=tokenize('input', min_token_len=2, drop_stopwords=True))
+
+    assert df['output'].to_list() == [['quick', 'brown', 'fox', 'ok'], ['tale', 'two', 'cities']]
+
+
+def test_tokenize_custom_pattern():
+    df = polars.DataFrame({
+        'input': ['red;green;;Blue'],
+    })
+    df = df.with_columns(output=tokenize('input', pattern=';'))
+
+    assert df['output'].to_list() == [['red', 'green', 'blue']]
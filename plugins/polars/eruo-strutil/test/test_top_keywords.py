@@ -0,0 +1,35 @@
+from eruo_strutil import top_keywords
+import polars
+
+
+def test_top_keywords():
+    df = polars.DataFrame({
+        'input': ['The cat and the dog. The dog barked at the cat; the bird sang.', '', None],
+        'expected': [['cat', 'dog', 'barked', 'bird', 'sang'], [], None],
+    })
+    df = df.with_columns(output=top_keywords('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_top_keywords_ties_break_alphabetically():
+    df = polars.DataFrame({
+        'input': ['zeta alpha beta alpha zeta gamma', 'gamma beta alpha'],
+        'expected': [['alpha', 'zeta'], ['alpha', 'beta']],
+    })
+    df = df.with_columns(output=top_keywords('input', n=2))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_top_keywords_stopwords():
+    df = polars.DataFrame({
+        'input': ['Polars is fast and polars is fun'],
+    })
+    df = df.with_columns(
+        default=top_keywords('input', n=2),
+        custom=top_keywords('input', n=2, stopwords=['polars'], min_len=1),
+    )
+
+    assert df['default'].to_list() == [['polars', 'fast']]
+    assert df['custom'].to_list() == [['is', 'and']]
@@ -0,0 +1,52 @@
+from eruo_strutil import ltrim_chars, rtrim_chars, trim_chars
+import polars
+
+
+def test_trim_chars():
+    df = polars.DataFrame({
+        'input': ['xyxabcyx', 'yabcx', 'abc', None],
+    })
+    df = df.with_columns(
+        both=trim_chars('input', characters='xy'),
+        left=ltrim_chars('input', characters='xy'),
+        right=rtrim_chars('input', characters='xy'),
+    )
+
+    assert df['both'].to_list() == ['abc', 'abc', 'abc', None]
+    assert df['left'].to_list() == ['abcyx', 'abcx', 'abc', None]
+    assert df['right'].to_list() == ['xyxabc', 'yabc', 'abc', None]
+
+
+def test_trim_chars_mixed_set():
+    df = polars.DataFrame({
+        'input': ['-_ * hello world * _-', '**a-b**'],
+        'expected': ['hello world', 'a-b'],
+    })
+    df = df.with_columns(output=trim_chars('input', characters=' *_-'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_trim_chars_only_trim_characters():
+    df = polars.DataFrame({
+        'input': ['xyyx', 'x'],
+    })
+    df = df.with_columns(
+        both=trim_chars('input', characters='xy'),
+        left=ltrim_chars('input', characters='xy'),
+        right=rtrim_chars('input', characters='xy'),
+    )
+
+    assert df['both'].to_list() == ['', '']
+    assert df['left'].to_list() == ['', '']
+    assert df['right'].to_list() == ['', '']
+
+
+def test_trim_chars_default_whitespace():
+    df = polars.DataFrame({
+        'input': ['\u00a0\t a b \n'],
+        'expected': ['a b'],
+    })
+    df = df.with_columns(output=trim_chars('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
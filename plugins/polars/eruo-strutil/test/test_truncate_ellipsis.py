@@ -0,0 +1,51 @@
+from eruo_strutil import truncate_ellipsis
+import polars
+import pytest
+
+
+def test_truncate_ellipsis_fits():
+    df = polars.DataFrame({
+        'input': ['short', 'exactly10!', None],
+    })
+    df = df.with_columns(output=truncate_ellipsis('input', length=10))
+
+    assert df['output'].to_list() == df['input'].to_list()
+
+
+def test_truncate_ellipsis_mid_word():
+    df = polars.DataFrame({
+        'input': ['Hello wonderful world'],
+        'expected': ['Hello w...'],
+    })
+    df = df.with_columns(output=truncate_ellipsis('input', length=10, ellipsis='...'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_truncate_ellipsis_word_boundary():
+    df = polars.DataFrame({
+        'input': ['Hello wonderful world', 'Supercalifragilistic'],
+        'expected': ['Hello...', 'Superca...'],
+    })
+    df = df.with_columns(output=truncate_ellipsis('input', length=10, ellipsis='...', word_boundary=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_truncate_ellipsis_graphemes():
+    df = polars.DataFrame({
+        'input': ['cafe\u0301 cafe\u0301 cafe\u0301'],
+        'expected': ['cafe\u0301…'],
+    })
+    df = df.with_columns(output=truncate_ellipsis('input', length=6))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_truncate_ellipsis_too_long():
+    df = polars.DataFrame({
+        'input': ['abc'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=truncate_ellipsis('input', length=2, ellipsis='...'))
@@ -0,0 +1,58 @@
+from eruo_strutil import url_decode, url_encode
+import polars
+import pytest
+
+
+def test_url_encode():
+    df = polars.DataFrame({
+        'input': ['a b&c=d/e?f#g', 'unreserved-._~', 'é', None],
+        'expected': ['a%20b%26c%3Dd%2Fe%3Ff%23g', 'unreserved-._~', '%C3%A9', None],
+    })
+    df = df.with_columns(output=url_encode('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_url_encode_spaces_as_plus():
+    df = polars.DataFrame({
+        'input': ['a b+c'],
+        'expected': ['a+b%2Bc'],
+    })
+    df = df.with_columns(output=url_encode('input', encode_spaces_as_plus=True))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_url_round_trip():
+    df = polars.DataFrame({
+        'input': ["!*'();:@&=+$,/?#[] %", 'héllo wörld', None],
+    })
+    df = df.with_columns(
+        default=url_decode(url_encode('input')),
+        form=url_decode(url_encode('input', encode_spaces_as_plus=True), encode_spaces_as_plus=True),
+    )
+
+    assert df['default'].to_list() == df['input'].to_list()
+    assert df['form'].to_list() == df['input'].to_list()
+
+
+def test_url_decode_plus():
+    df = polars.DataFrame({
+        'input': ['a+b%20c'],
+    })
+    df = df.with_columns(
+        default=url_decode('input'),
+        form=url_decode('input', encode_spaces_as_plus=True),
+    )
+
+    assert df['default'].to_list() == ['a+b c']
+    assert df['form'].to_list() == ['a b c']
+
+
+def test_url_decode_malformed():
+    df = polars.DataFrame({
+        'input': ['%G1'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=url_decode('input'))
@@ -0,0 +1,31 @@
+from eruo_strutil import to_fullwidth, to_halfwidth
+import polars
+
+
+def test_to_fullwidth():
+    df = polars.DataFrame({
+        'input': ['ABC123', 'Tokyo 2020!', '東京', None],
+        'expected': ['ＡＢＣ１２３', 'Ｔｏｋｙｏ\u3000２０２０！', '東京', None],
+    })
+    df = df.with_columns(output=to_fullwidth('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_to_halfwidth():
+    df = polars.DataFrame({
+        'input': ['ＡＢＣ１２３', 'Ｔｏｋｙｏ\u3000２０２０！', '東京', None],
+        'expected': ['ABC123', 'Tokyo 2020!', '東京', None],
+    })
+    df = df.with_columns(output=to_halfwidth('input'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_width_round_trip():
+    df = polars.DataFrame({
+        'input': ['Order #A12 は 3個', 'ＡＢＣ abc １２３'],
+    })
+    df = df.with_columns(output=to_halfwidth(to_fullwidth('input')))
+
+    assert df['output'].to_list() == ['Order #A12 は 3個', 'ABC abc 123']
@@ -0,0 +1,33 @@
+from eruo_strutil import word_count
+import polars
+import pytest
+
+
+def test_word_count():
+    df = polars.DataFrame({
+        'input': ['the quick brown fox', 'a  b   c', '  leading and trailing  ', '', '   ', None],
+        'expected': [4, 3, 3, 0, 0, None],
+    })
+    df = df.with_columns(output=word_count('input'))
+
+    assert df['output'].dtype == polars.UInt32
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_word_count_pattern():
+    df = polars.DataFrame({
+        'input': ['red,green;;blue', ',,', 'one two'],
+        'expected': [3, 0, 1],
+    })
+    df = df.with_columns(output=word_count('input', pattern='[,;]'))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_word_count_invalid_pattern():
+    df = polars.DataFrame({
+        'input': ['a'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=word_count('input', pattern='('))
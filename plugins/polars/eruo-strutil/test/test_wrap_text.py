@@ -0,0 +1,45 @@
+from eruo_strutil import wrap_text
+import polars
+import pytest
+
+
+def test_wrap_text():
+    df = polars.DataFrame({
+        'input': ['the quick brown fox jumps', 'short', '', None],
+        'expected': ['the quick\nbrown fox\njumps', 'short', '', None],
+    })
+    df = df.with_columns(output=wrap_text('input', width=10))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_wrap_text_paragraphs():
+    df = polars.DataFrame({
+        'input': ['first paragraph here\n\nsecond one'],
+        'expected': ['first\nparagraph\nhere\n\nsecond one'],
+    })
+    df = df.with_columns(output=wrap_text('input', width=10))
+
+    assert df['output'].to_list() == df['expected'].to_list()
+
+
+def test_wrap_text_long_word():
+    df = polars.DataFrame({
+        'input': ['see https://example.com/long ok'],
+    })
+    df = df.with_columns(
+        broken=wrap_text('input', width=10),
+        unbroken=wrap_text('input', width=10, break_long_words=False),
+    )
+
+    assert df['broken'].to_list() == ['see\nhttps://ex\nample.com/\nlong ok']
+    assert df['unbroken'].to_list() == ['see\nhttps://example.com/long\nok']
+
+
+def test_wrap_text_zero_width():
+    df = polars.DataFrame({
+        'input': ['a'],
+    })
+
+    with pytest.raises(polars.exceptions.ComputeError):
+        df.with_columns(output=wrap_text('input', width=0))
@@ -31,16 +31,587 @@ try:
         def __init__(self, expr: polars.Expr) -> None:
             self._expr = expr
 
-        def pig_latinnify(self) -> polars.Expr:
-            return strx.pig_latinnify(self._expr)
+        def pig_latinnify(self,
+                          vowel_suffix:     str = 'way',
+                          consonant_suffix: str = 'ay',
+                          treat_y_as_vowel: bool = False,
+                          null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.pig_latinnify(self._expr, vowel_suffix, consonant_suffix, treat_y_as_vowel, null_mode)
 
-        def split_by_chars(self, characters: str) -> polars.Expr:
-            return strx.split_by_chars(self._expr, characters)
+        def split_by_chars(self,
+                           characters: str,
+                           keep_empty: bool = True,
+                           maxsplit:   int = None,
+                           null_mode:  str = 'propagate') -> polars.Expr:
+            return strx.split_by_chars(self._expr, characters, keep_empty, maxsplit, null_mode)
 
-        def to_sentence_case(self) -> polars.Expr:
-            return strx.to_sentence_case(self._expr)
+        def split_by_regex(self,
+                           pattern:   str,
+                           null_mode: str = 'propagate') -> polars.Expr:
+            return strx.split_by_regex(self._expr, pattern, null_mode)
 
-        def to_sponge_case(self) -> polars.Expr:
-            return strx.to_sponge_case(self._expr)
+        def to_sentence_case(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.to_sentence_case(self._expr, null_mode)
+
+        def to_sponge_case(self,
+                           seed:              int = None,
+                           upper_probability: float = 0.5,
+                           null_mode:         str = 'propagate') -> polars.Expr:
+            return strx.to_sponge_case(self._expr, seed, upper_probability, null_mode)
+
+        def to_camel_case(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.to_camel_case(self._expr, null_mode)
+
+        def to_pascal_case(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.to_pascal_case(self._expr, null_mode)
+
+        def to_snake_case(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.to_snake_case(self._expr, null_mode)
+
+        def to_kebab_case(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.to_kebab_case(self._expr, null_mode)
+
+        def to_constant_case(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.to_constant_case(self._expr, null_mode)
+
+        def to_title_case(self,
+                          stop_words: list[str] = None,
+                          null_mode:  str = 'propagate') -> polars.Expr:
+            return strx.to_title_case(self._expr, stop_words, null_mode)
+
+        def to_swap_case(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.to_swap_case(self._expr, null_mode)
+
+        def normalize_nfc(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.normalize_nfc(self._expr, null_mode)
+
+        def normalize_nfd(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.normalize_nfd(self._expr, null_mode)
+
+        def normalize_nfkc(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.normalize_nfkc(self._expr, null_mode)
+
+        def normalize_nfkd(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.normalize_nfkd(self._expr, null_mode)
+
+        def remove_diacritics(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.remove_diacritics(self._expr, null_mode)
+
+        def slugify(self,
+                    separator:  str = '-',
+                    lowercase:  bool = True,
+                    max_length: int = None,
+                    null_mode:  str = 'propagate') -> polars.Expr:
+            return strx.slugify(self._expr, separator, lowercase, max_length, null_mode)
+
+        def base64_encode(self,
+                          url_safe:  bool = False,
+                          null_mode: str = 'propagate') -> polars.Expr:
+            return strx.base64_encode(self._expr, url_safe, null_mode)
+
+        def base64_decode(self,
+                          url_safe:  bool = False,
+                          strict:    bool = True,
+                          null_mode: str = 'propagate') -> polars.Expr:
+            return strx.base64_decode(self._expr, url_safe, strict, null_mode)
+
+        def hex_encode(self,
+                       uppercase: bool = False,
+                       null_mode: str = 'propagate') -> polars.Expr:
+            return strx.hex_encode(self._expr, uppercase, null_mode)
+
+        def hex_decode(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.hex_decode(self._expr, null_mode)
+
+        def url_encode(self,
+                       encode_spaces_as_plus: bool = False,
+                       null_mode:             str = 'propagate') -> polars.Expr:
+            return strx.url_encode(self._expr, encode_spaces_as_plus, null_mode)
+
+        def url_decode(self,
+                       encode_spaces_as_plus: bool = False,
+                       null_mode:             str = 'propagate') -> polars.Expr:
+            return strx.url_decode(self._expr, encode_spaces_as_plus, null_mode)
+
+        def html_escape(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.html_escape(self._expr, null_mode)
+
+        def html_unescape(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.html_unescape(self._expr, null_mode)
+
+        def replace_regex(self,
+                          pattern:          str,
+                          replacement:      str,
+                          case_insensitive: bool = False,
+                          replace_all:      bool = True,
+                          null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.replace_regex(self._expr, pattern, replacement, case_insensitive, replace_all, null_mode)
+
+        def extract_regex(self,
+                          pattern:   str,
+                          group:     int = 1,
+                          null_mode: str = 'propagate') -> polars.Expr:
+            return strx.extract_regex(self._expr, pattern, group, null_mode)
+
+        def extract_all_regex(self,
+                              pattern:   str,
+                              group:     int = 0,
+                              null_mode: str = 'propagate') -> polars.Expr:
+            return strx.extract_all_regex(self._expr, pattern, group, null_mode)
+
+        def pad_start(self,
+                      length:    int,
+                      fill:      str = ' ',
+                      null_mode: str = 'propagate') -> polars.Expr:
+            return strx.pad_start(self._expr, length, fill, null_mode)
+
+        def pad_end(self,
+                    length:    int,
+                    fill:      str = ' ',
+                    null_mode: str = 'propagate') -> polars.Expr:
+            return strx.pad_end(self._expr, length, fill, null_mode)
+
+        def center(self,
+                   length:    int,
+                   fill:      str = ' ',
+                   null_mode: str = 'propagate') -> polars.Expr:
+            return strx.center(self._expr, length, fill, null_mode)
+
+        def trim_chars(self,
+                       characters: str = '',
+                       null_mode:  str = 'propagate') -> polars.Expr:
+            return strx.trim_chars(self._expr, characters, null_mode)
+
+        def ltrim_chars(self,
+                        characters: str = '',
+                        null_mode:  str = 'propagate') -> polars.Expr:
+            return strx.ltrim_chars(self._expr, characters, null_mode)
+
+        def rtrim_chars(self,
+                        characters: str = '',
+                        null_mode:  str = 'propagate') -> polars.Expr:
+            return strx.rtrim_chars(self._expr, characters, null_mode)
+
+        def squeeze_whitespace(self,
+                               keep_char: str = '',
+                               null_mode: str = 'propagate') -> polars.Expr:
+            return strx.squeeze_whitespace(self._expr, keep_char, null_mode)
+
+        def reverse_string(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.reverse_string(self._expr, null_mode)
+
+        def word_count(self,
+                       pattern:   str = None,
+                       null_mode: str = 'propagate') -> polars.Expr:
+            return strx.word_count(self._expr, pattern, null_mode)
+
+        def grapheme_len(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.grapheme_len(self._expr, null_mode)
+
+        def truncate_ellipsis(self,
+                              length:        int,
+                              ellipsis:      str = '…',
+                              word_boundary: bool = False,
+                              null_mode:     str = 'propagate') -> polars.Expr:
+            return strx.truncate_ellipsis(self._expr, length, ellipsis, word_boundary, null_mode)
+
+        def wrap_text(self,
+                      width:            int,
+                      break_long_words: bool = True,
+                      null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.wrap_text(self._expr, width, break_long_words, null_mode)
+
+        def levenshtein(self,
+                        other:     polars.Expr,
+                        null_mode: str = 'propagate') -> polars.Expr:
+            return strx.levenshtein(self._expr, other, null_mode)
+
+        def jaro_winkler(self,
+                         other:         polars.Expr,
+                         prefix_weight: float = 0.1,
+                         null_mode:     str = 'propagate') -> polars.Expr:
+            return strx.jaro_winkler(self._expr, other, prefix_weight, null_mode)
+
+        def hamming_distance(self,
+                             other:              polars.Expr,
+                             on_length_mismatch: str = 'error',
+                             fill:               str = ' ',
+                             null_mode:          str = 'propagate') -> polars.Expr:
+            return strx.hamming_distance(self._expr, other, on_length_mismatch, fill, null_mode)
+
+        def soundex(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.soundex(self._expr, null_mode)
+
+        def metaphone(self,
+                      max_length: int = 4,
+                      null_mode:  str = 'propagate') -> polars.Expr:
+            return strx.metaphone(self._expr, max_length, null_mode)
+
+        def ngrams(self,
+                   n:          int,
+                   char_level: bool = False,
+                   separator:  str = ' ',
+                   null_mode:  str = 'propagate') -> polars.Expr:
+            return strx.ngrams(self._expr, n, char_level, separator, null_mode)
+
+        def count_substring(self,
+                            needle:           str,
+                            overlapping:      bool = False,
+                            case_insensitive: bool = False,
+                            null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.count_substring(self._expr, needle, overlapping, case_insensitive, null_mode)
+
+        def find_all_positions(self,
+                               needle:           str,
+                               case_insensitive: bool = False,
+                               null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.find_all_positions(self._expr, needle, case_insensitive, null_mode)
+
+        def mask(self,
+                 keep_start: int = 0,
+                 keep_end:   int = 0,
+                 mask_char:  str = '*',
+                 null_mode:  str = 'propagate') -> polars.Expr:
+            return strx.mask(self._expr, keep_start, keep_end, mask_char, null_mode)
+
+        def mask_email(self,
+                       reveal_first: int = 1,
+                       mask_invalid: bool = False,
+                       null_mode:    str = 'propagate') -> polars.Expr:
+            return strx.mask_email(self._expr, reveal_first, mask_invalid, null_mode)
+
+        def extract_emails(self,
+                           dedupe:    bool = False,
+                           null_mode: str = 'propagate') -> polars.Expr:
+            return strx.extract_emails(self._expr, dedupe, null_mode)
+
+        def extract_urls(self,
+                         schemes:   list[str] = None,
+                         null_mode: str = 'propagate') -> polars.Expr:
+            return strx.extract_urls(self._expr, schemes, null_mode)
+
+        def extract_numbers(self,
+                            allow_thousands_separator: bool = False,
+                            null_mode:                 str = 'propagate') -> polars.Expr:
+            return strx.extract_numbers(self._expr, allow_thousands_separator, null_mode)
+
+        def number_to_words(self,
+                            use_and:   bool = False,
+                            null_mode: str = 'propagate') -> polars.Expr:
+            return strx.number_to_words(self._expr, use_and, null_mode)
+
+        def ordinalize(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.ordinalize(self._expr, null_mode)
+
+        def roman_encode(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.roman_encode(self._expr, null_mode)
+
+        def roman_decode(self,
+                         strict:    bool = True,
+                         null_mode: str = 'propagate') -> polars.Expr:
+            return strx.roman_decode(self._expr, strict, null_mode)
+
+        def repeat(self,
+                   count:     int,
+                   separator: str = '',
+                   null_mode: str = 'propagate') -> polars.Expr:
+            return strx.repeat(self._expr, count, separator, null_mode)
+
+        def strip_html(self,
+                       drop_script_style: bool = True,
+                       null_mode:         str = 'propagate') -> polars.Expr:
+            return strx.strip_html(self._expr, drop_script_style, null_mode)
+
+        def remove_emoji(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.remove_emoji(self._expr, null_mode)
+
+        def extract_emoji(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.extract_emoji(self._expr, null_mode)
+
+        def strip_zero_width(self,
+                             strip_directional: bool = False,
+                             null_mode:         str = 'propagate') -> polars.Expr:
+            return strx.strip_zero_width(self._expr, strip_directional, null_mode)
+
+        def rot_n(self,
+                  shift:     int = 13,
+                  null_mode: str = 'propagate') -> polars.Expr:
+            return strx.rot_n(self._expr, shift, null_mode)
+
+        def is_palindrome(self,
+                          ignore_case:      bool = False,
+                          ignore_non_alnum: bool = False,
+                          null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.is_palindrome(self._expr, ignore_case, ignore_non_alnum, null_mode)
+
+        def initials(self,
+                     uppercase: bool = True,
+                     separator: str = '',
+                     max_count: int = None,
+                     null_mode: str = 'propagate') -> polars.Expr:
+            return strx.initials(self._expr, uppercase, separator, max_count, null_mode)
+
+        def to_ascii(self,
+                     replacement: str = '?',
+                     null_mode:   str = 'propagate') -> polars.Expr:
+            return strx.to_ascii(self._expr, replacement, null_mode)
+
+        def longest_common_prefix(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.longest_common_prefix(self._expr, null_mode)
+
+        def remove_suffix(self,
+                          suffix:           str,
+                          case_insensitive: bool = False,
+                          null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.remove_suffix(self._expr, suffix, case_insensitive, null_mode)
+
+        def remove_prefix(self,
+                          prefix:           str,
+                          case_insensitive: bool = False,
+                          null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.remove_prefix(self._expr, prefix, case_insensitive, null_mode)
+
+        def detect_case(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.detect_case(self._expr, null_mode)
+
+        def caseless_equals(self,
+                            other:     polars.Expr,
+                            null_mode: str = 'propagate') -> polars.Expr:
+            return strx.caseless_equals(self._expr, other, null_mode)
+
+        def similarity_ratio(self,
+                             other:     polars.Expr,
+                             null_mode: str = 'propagate') -> polars.Expr:
+            return strx.similarity_ratio(self._expr, other, null_mode)
+
+        def tokenize(self,
+                     pattern:        str = r'\W+',
+                     lowercase:      bool = True,
+                     min_token_len:  int = 1,
+                     drop_stopwords: bool = False,
+                     null_mode:      str = 'propagate') -> polars.Expr:
+            return strx.tokenize(self._expr, pattern, lowercase, min_token_len, drop_stopwords, null_mode)
+
+        def morse_encode(self,
+                         letter_sep:       str = ' ',
+                         word_sep:         str = ' / ',
+                         skip_unsupported: bool = False,
+                         null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.morse_encode(self._expr, letter_sep, word_sep, skip_unsupported, null_mode)
+
+        def morse_decode(self,
+                         letter_sep:       str = ' ',
+                         word_sep:         str = ' / ',
+                         skip_unsupported: bool = False,
+                         null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.morse_decode(self._expr, letter_sep, word_sep, skip_unsupported, null_mode)
+
+        def to_leetspeak(self,
+                         level:     int = 1,
+                         null_mode: str = 'propagate') -> polars.Expr:
+            return strx.to_leetspeak(self._expr, level, null_mode)
+
+        def to_fullwidth(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.to_fullwidth(self._expr, null_mode)
+
+        def to_halfwidth(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.to_halfwidth(self._expr, null_mode)
+
+        def expand_contractions(self,
+                                extra:     list[tuple[str, str]] = None,
+                                null_mode: str = 'propagate') -> polars.Expr:
+            return strx.expand_contractions(self._expr, extra, null_mode)
+
+        def normalize_quotes(self,
+                             to_straight: bool = True,
+                             dashes:      bool = False,
+                             null_mode:   str = 'propagate') -> polars.Expr:
+            return strx.normalize_quotes(self._expr, to_straight, dashes, null_mode)
+
+        def char_stats(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.char_stats(self._expr, null_mode)
+
+        def count_vowels(self,
+                         treat_y_as_vowel: bool = False,
+                         ascii_only:       bool = False,
+                         null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.count_vowels(self._expr, treat_y_as_vowel, ascii_only, null_mode)
+
+        def count_consonants(self,
+                             treat_y_as_vowel: bool = False,
+                             ascii_only:       bool = False,
+                             null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.count_consonants(self._expr, treat_y_as_vowel, ascii_only, null_mode)
+
+        def syllable_count(self,
+                           per_word:  bool = False,
+                           null_mode: str = 'propagate') -> polars.Expr:
+            return strx.syllable_count(self._expr, per_word, null_mode)
+
+        def flesch_reading_ease(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.flesch_reading_ease(self._expr, null_mode)
+
+        def detect_script(self,
+                          threshold: float = 0.6,
+                          null_mode: str = 'propagate') -> polars.Expr:
+            return strx.detect_script(self._expr, threshold, null_mode)
+
+        def redact_pii(self,
+                       redact_emails: bool = True,
+                       redact_phones: bool = True,
+                       redact_ssn:    bool = True,
+                       mask_char:     str = '*',
+                       null_mode:     str = 'propagate') -> polars.Expr:
+            return strx.redact_pii(self._expr, redact_emails, redact_phones, redact_ssn, mask_char, null_mode)
+
+        def fuzzy_contains(self,
+                           needle:           str,
+                           max_distance:     int = 1,
+                           case_insensitive: bool = False,
+                           null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.fuzzy_contains(self._expr, needle, max_distance, case_insensitive, null_mode)
+
+        def split_words(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.split_words(self._expr, null_mode)
+
+        def search_key(self,
+                       normalize:          bool = True,
+                       remove_diacritics:  bool = True,
+                       case_fold:          bool = True,
+                       squeeze_whitespace: bool = True,
+                       null_mode:          str = 'propagate') -> polars.Expr:
+            return strx.search_key(self._expr, normalize, remove_diacritics, case_fold, squeeze_whitespace, null_mode)
+
+        def pluralize(self,
+                      extra_irregulars: list[tuple[str, str]] = None,
+                      null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.pluralize(self._expr, extra_irregulars, null_mode)
+
+        def singularize(self,
+                        extra_irregulars: list[tuple[str, str]] = None,
+                        null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.singularize(self._expr, extra_irregulars, null_mode)
+
+        def to_name_case(self,
+                         mc_rule:   bool = True,
+                         null_mode: str = 'propagate') -> polars.Expr:
+            return strx.to_name_case(self._expr, mc_rule, null_mode)
+
+        def to_alternating_case(self,
+                                start_upper:   bool = False,
+                                skip_nonalpha: bool = False,
+                                null_mode:     str = 'propagate') -> polars.Expr:
+            return strx.to_alternating_case(self._expr, start_upper, skip_nonalpha, null_mode)
+
+        def strip_accents(self,
+                          preserve:  list[str] = None,
+                          null_mode: str = 'propagate') -> polars.Expr:
+            return strx.strip_accents(self._expr, preserve, null_mode)
+
+        def fit_width(self,
+                      width:     int,
+                      align:     str = 'left',
+                      fill:      str = ' ',
+                      truncate:  bool = False,
+                      null_mode: str = 'propagate') -> polars.Expr:
+            return strx.fit_width(self._expr, width, align, fill, truncate, null_mode)
+
+        def top_keywords(self,
+                         n:         int = 5,
+                         stopwords: list[str] = None,
+                         min_len:   int = 3,
+                         null_mode: str = 'propagate') -> polars.Expr:
+            return strx.top_keywords(self._expr, n, stopwords, min_len, null_mode)
+
+        def normalize_phone(self,
+                            default_country_code: str = '1',
+                            output_format:        str = 'e164',
+                            strict:               bool = False,
+                            null_mode:            str = 'propagate') -> polars.Expr:
+            return strx.normalize_phone(self._expr, default_country_code, output_format, strict, null_mode)
+
+        def parse_bool(self,
+                       strict:    bool = False,
+                       null_mode: str = 'propagate') -> polars.Expr:
+            return strx.parse_bool(self._expr, strict, null_mode)
+
+        def hash_string(self,
+                        algorithm: str = 'sha256',
+                        output:    str = 'hex',
+                        null_mode: str = 'propagate') -> polars.Expr:
+            return strx.hash_string(self._expr, algorithm, output, null_mode)
+
+        def base32_encode(self,
+                          padding:   bool = True,
+                          lowercase: bool = False,
+                          null_mode: str = 'propagate') -> polars.Expr:
+            return strx.base32_encode(self._expr, padding, lowercase, null_mode)
+
+        def base32_decode(self,
+                          strict:    bool = True,
+                          null_mode: str = 'propagate') -> polars.Expr:
+            return strx.base32_decode(self._expr, strict, null_mode)
+
+        def fix_mojibake(self,
+                         aggressive: bool = False,
+                         null_mode:  str = 'propagate') -> polars.Expr:
+            return strx.fix_mojibake(self._expr, aggressive, null_mode)
+
+        def split_fixed(self,
+                        separator: str,
+                        n:         int,
+                        fill:      str = None,
+                        null_mode: str = 'propagate') -> polars.Expr:
+            return strx.split_fixed(self._expr, separator, n, fill, null_mode)
+
+        def chunk(self,
+                  size:      int,
+                  grapheme:  bool = False,
+                  null_mode: str = 'propagate') -> polars.Expr:
+            return strx.chunk(self._expr, size, grapheme, null_mode)
+
+        def group_digits(self,
+                         group_size:  int = 3,
+                         group_sep:   str = ',',
+                         decimal_sep: str = '.',
+                         strict:      bool = False,
+                         null_mode:   str = 'propagate') -> polars.Expr:
+            return strx.group_digits(self._expr, group_size, group_sep, decimal_sep, strict, null_mode)
+
+        def dedupe_tokens(self,
+                          separator: str = ',',
+                          keep:      str = 'first',
+                          null_mode: str = 'propagate') -> polars.Expr:
+            return strx.dedupe_tokens(self._expr, separator, keep, null_mode)
+
+        def sort_tokens(self,
+                        separator:        str = ',',
+                        descending:       bool = False,
+                        case_insensitive: bool = False,
+                        numeric:          bool = False,
+                        null_mode:        str = 'propagate') -> polars.Expr:
+            return strx.sort_tokens(self._expr, separator, descending, case_insensitive, numeric, null_mode)
+
+        def anagram_key(self,
+                        ignore_case:        bool = True,
+                        ignore_non_letters: bool = True,
+                        null_mode:          str = 'propagate') -> polars.Expr:
+            return strx.anagram_key(self._expr, ignore_case, ignore_non_letters, null_mode)
+
+        def guess_encoding(self, null_mode: str = 'propagate') -> polars.Expr:
+            return strx.guess_encoding(self._expr, null_mode)
+
+        def decode_bytes(self,
+                         encoding:  str,
+                         errors:    str = 'strict',
+                         null_mode: str = 'propagate') -> polars.Expr:
+            return strx.decode_bytes(self._expr, encoding, errors, null_mode)
+
+        def best_match(self,
+                       candidates: list[str],
+                       metric:     str = 'jaro_winkler',
+                       threshold:  float = 0.8,
+                       null_mode:  str = 'propagate') -> polars.Expr:
+            return strx.best_match(self._expr, candidates, metric, threshold, null_mode)
 except ModuleNotFoundError:
     pass
\ No newline at end of file
@@ -187,7 +187,7 @@ def _get_operation_expression(left:   polars.Expr,
         case 'snake-case'                             : return _get_change_case_to_snake_case_expression(left)
         case 'sentence-case'                          : return left.strx.to_sentence_case()
         case 'slugify'                                : return _get_slugify_expression(left)
-        case 'split-by-characters'                    : return left.strx.split_by_chars(args[0])
+        case 'split-by-characters'                    : return left.strx.split_by_chars(args[0]).explode()
         case 'sponge-case'                            : return left.strx.to_sponge_case()
         case 'title-case'                             : return left.str.to_titlecase()
         case 'unicode-normalization-nfc'              : return left.str.normalize('NFC')